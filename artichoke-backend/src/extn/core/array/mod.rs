@@ -0,0 +1,3 @@
+pub mod mruby;
+
+pub use mruby::init;
@@ -0,0 +1,7 @@
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    let _ = interp.eval(&include_bytes!("array.rb")[..])?;
+    trace!("Patched Array onto interpreter");
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use crate::extn::prelude::*;
+
+pub mod array;
+pub mod random;
+pub mod securerandom;
+
+/// Load every `core` extension module onto a fresh interpreter.
+///
+/// `random` loads first so `array`'s `shuffle`/`sample` shim can reference
+/// `Random::DEFAULT`, and `securerandom` loads alongside `random` since
+/// `SecureRandom` reuses its OS-entropy primitives.
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    random::init(interp)?;
+    securerandom::init(interp)?;
+    array::init(interp)?;
+    Ok(())
+}
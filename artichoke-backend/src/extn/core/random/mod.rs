@@ -0,0 +1,442 @@
+use std::fmt;
+use std::process;
+
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+use crate::extn::prelude::*;
+
+mod isaac;
+pub mod mruby;
+pub mod trampoline;
+
+use isaac::IsaacRng;
+pub use mruby::init;
+
+/// The PRNG algorithm backing a [`Random`].
+///
+/// `MersenneTwister` is the default. Despite its name it is backed by
+/// `rand`'s [`StdRng`], not by a Mersenne Twister: it yields the same
+/// sequence for the same seed, but that sequence does not match MRI's.
+/// `Isaac` selects the cryptographically-motivated ISAAC generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    MersenneTwister,
+    Isaac,
+}
+
+impl Default for Algorithm {
+    fn default() -> Self {
+        Self::MersenneTwister
+    }
+}
+
+/// The selected PRNG implementation, seeded and ready to draw from.
+#[derive(Clone)]
+enum Backend {
+    MersenneTwister(StdRng),
+    Isaac(IsaacRng),
+}
+
+impl Backend {
+    fn new(algorithm: Algorithm, seed: u64) -> Self {
+        match algorithm {
+            Algorithm::MersenneTwister => Self::MersenneTwister(StdRng::seed_from_u64(seed)),
+            Algorithm::Isaac => Self::Isaac(IsaacRng::from_seed(seed)),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Self::MersenneTwister(rng) => rng.next_u64(),
+            Self::Isaac(rng) => {
+                let lo = u64::from(rng.next_u32());
+                let hi = u64::from(rng.next_u32());
+                (hi << 32) | lo
+            }
+        }
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        match self {
+            Self::MersenneTwister(rng) => rng.fill_bytes(buf),
+            Self::Isaac(rng) => {
+                for chunk in buf.chunks_mut(4) {
+                    let word = rng.next_u32().to_ne_bytes();
+                    chunk.copy_from_slice(&word[..chunk.len()]);
+                }
+            }
+        }
+    }
+}
+
+/// Opt-in auto-reseeding policy for a [`Random`].
+///
+/// Long-lived `Random` instances constructed with a `reseed_after` budget
+/// periodically refresh their internal state from OS entropy, limiting the
+/// damage of state compromise. The wrapper also detects a `fork` (by
+/// comparing the current PID against the PID recorded at construction) and
+/// forces an immediate reseed before producing output, guarding against
+/// fork-induced stream duplication.
+#[derive(Debug, Clone, Copy)]
+struct ReseedPolicy {
+    /// The number of output bytes to produce before reseeding.
+    budget_bytes: u64,
+    /// The number of output bytes remaining before the next reseed.
+    remaining_bytes: u64,
+    /// The PID recorded at construction (or at the last reseed).
+    pid: u32,
+}
+
+/// The Rust-backed `Random` object embedded in mruby's `Random` class.
+///
+/// `Random` wraps a seedable, reproducible PRNG. The default algorithm is a
+/// Mersenne-Twister-compatible stream via [`StdRng`], which keeps `rand`
+/// sequences reproducible across runs given the same seed.
+#[derive(Clone)]
+pub struct Random {
+    seed: u64,
+    algorithm: Algorithm,
+    backend: Backend,
+    /// The second of the pair of standard normal variates produced by the
+    /// polar Box–Muller transform, cached so the next call to `normal` is
+    /// free.
+    cached_gauss: Option<f64>,
+    reseed_policy: Option<ReseedPolicy>,
+}
+
+impl fmt::Debug for Random {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Random").field("seed", &self.seed).finish()
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::with_seed(Self::new_seed())
+    }
+}
+
+impl Random {
+    /// Construct a `Random` seeded deterministically from `seed`, using the
+    /// default algorithm ([`Algorithm::MersenneTwister`], backed by
+    /// [`StdRng`]).
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_seed_and_algorithm(seed, Algorithm::default())
+    }
+
+    /// Construct a `Random` seeded deterministically from `seed`, using the
+    /// given PRNG `algorithm`.
+    #[must_use]
+    pub fn with_seed_and_algorithm(seed: u64, algorithm: Algorithm) -> Self {
+        Self {
+            seed,
+            algorithm,
+            backend: Backend::new(algorithm, seed),
+            cached_gauss: None,
+            reseed_policy: None,
+        }
+    }
+
+    /// Construct a `Random` that automatically reseeds itself from OS
+    /// entropy after producing `reseed_after` bytes of output, and whenever
+    /// a `fork` is detected.
+    #[must_use]
+    pub fn with_seed_and_reseed_after(seed: u64, reseed_after: u64) -> Self {
+        Self::with_seed_algorithm_and_reseed_after(seed, Algorithm::default(), reseed_after)
+    }
+
+    /// Construct a `Random` using the given PRNG `algorithm` that
+    /// automatically reseeds itself from OS entropy after producing
+    /// `reseed_after` bytes of output, and whenever a `fork` is detected.
+    #[must_use]
+    pub fn with_seed_algorithm_and_reseed_after(
+        seed: u64,
+        algorithm: Algorithm,
+        reseed_after: u64,
+    ) -> Self {
+        let mut random = Self::with_seed_and_algorithm(seed, algorithm);
+        random.reseed_policy = Some(ReseedPolicy {
+            budget_bytes: reseed_after,
+            remaining_bytes: reseed_after,
+            pid: process::id(),
+        });
+        random
+    }
+
+    /// Reinitialize this generator's internal state from a fresh OS entropy
+    /// draw, resetting the reseed budget and recorded PID.
+    fn reseed(&mut self) {
+        let seed = Self::new_seed();
+        self.seed = seed;
+        self.backend = Backend::new(self.algorithm, seed);
+        self.cached_gauss = None;
+        if let Some(policy) = &mut self.reseed_policy {
+            policy.remaining_bytes = policy.budget_bytes;
+            policy.pid = process::id();
+        }
+    }
+
+    /// Charge `consumed` bytes of output against the reseed budget (if any),
+    /// reseeding immediately if a fork is detected or the budget is
+    /// exhausted.
+    ///
+    /// Must be called before every draw that consumes PRNG output.
+    fn charge_reseed_budget(&mut self, consumed: u64) {
+        let Some(policy) = self.reseed_policy else {
+            return;
+        };
+
+        if policy.pid != process::id() {
+            self.reseed();
+            return;
+        }
+
+        match policy.remaining_bytes.checked_sub(consumed) {
+            Some(0) => self.reseed(),
+            Some(remaining) => {
+                self.reseed_policy = Some(ReseedPolicy {
+                    remaining_bytes: remaining,
+                    ..policy
+                });
+            }
+            None => self.reseed(),
+        }
+    }
+
+    /// The seed this `Random` was constructed with, or, after an automatic
+    /// reseed, the seed drawn for that reseed.
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Construct the `Random` instance backing the interpreter-wide
+    /// `Random::DEFAULT` constant used by `Kernel#rand` and friends.
+    #[must_use]
+    pub fn interpreter_prng_delegate() -> Self {
+        Self::with_seed(Self::new_seed())
+    }
+
+    /// Draw a fresh seed from OS entropy, the same source backing
+    /// `Random.urandom` and `Kernel#rand`'s implicit seeding.
+    #[must_use]
+    pub fn new_seed() -> u64 {
+        let mut buf = [0; 8];
+        getrandom::getrandom(&mut buf).expect("OS entropy source must be available");
+        u64::from_ne_bytes(buf)
+    }
+
+    /// Fill `buf` with random bytes drawn from this generator's stream.
+    pub fn bytes(&mut self, buf: &mut [u8]) {
+        self.charge_reseed_budget(buf.len() as u64);
+        self.backend.fill_bytes(buf);
+    }
+
+    /// Draw a random `f64` in `[0, 1)`.
+    #[must_use]
+    pub fn next_f64(&mut self) -> f64 {
+        self.charge_reseed_budget(8);
+        // 53 bits of randomness, matching the precision of an `f64` mantissa.
+        let bits = self.backend.next_u64() >> 11;
+        (bits as f64) * (1.0 / (1_u64 << 53) as f64)
+    }
+
+    /// Draw a random integer in `[0, max)`.
+    #[must_use]
+    pub fn next_int_in_range(&mut self, max: i64) -> i64 {
+        if max <= 0 {
+            return 0;
+        }
+        self.charge_reseed_budget(8);
+        // Lemire's method: multiply a fresh word by `max` and take the high
+        // 64 bits, rejecting draws whose low 64 bits fall in the remainder
+        // zone `[0, 2^64 % max)` to avoid modulo bias.
+        let max = max as u64;
+        let threshold = max.wrapping_neg() % max;
+        loop {
+            let product = u128::from(self.backend.next_u64()) * u128::from(max);
+            if product as u64 >= threshold {
+                return (product >> 64) as i64;
+            }
+        }
+    }
+    /// Shuffle `items` in place using a seeded Fisher–Yates shuffle.
+    ///
+    /// This is the primitive behind `Array#shuffle(random: rng)`: iterate
+    /// `i` from `len - 1` down to `1`, draw `j` in `[0, i]` via
+    /// [`next_int_in_range`](Self::next_int_in_range)'s Lemire-style
+    /// rejection sampling, and swap elements `i` and `j`, giving a
+    /// reproducible shuffle under a fixed seed.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.next_int_in_range((i + 1) as i64) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// Partially shuffle the first `n` elements of `items` into a uniformly
+    /// random sample, using a partial Fisher–Yates shuffle that stops after
+    /// `n` swaps.
+    ///
+    /// This is the primitive behind `Array#sample(n, random: rng)`: after
+    /// calling this, `items[..n.min(items.len())]` is the sample.
+    pub fn partial_shuffle<T>(&mut self, items: &mut [T], n: usize) {
+        let len = items.len();
+        let n = n.min(len);
+        for i in 0..n {
+            let j = i + self.next_int_in_range((len - i) as i64) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// Draw a uniform `f64` in `(0, 1]`, as needed by the transforms below,
+    /// which divide by or take the logarithm of the draw.
+    fn next_f64_open_closed(&mut self) -> f64 {
+        1.0 - self.next_f64()
+    }
+
+    /// Draw from the normal (Gaussian) distribution with the given `mean`
+    /// and `stddev`, using the polar Box–Muller transform.
+    ///
+    /// Each pair of uniform draws produces two standard normal variates; the
+    /// second is cached on `self` to serve the next call with zero extra
+    /// uniforms.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ArgumentError`] if `stddev` is not positive.
+    pub fn normal(&mut self, mean: f64, stddev: f64) -> Result<f64, Error> {
+        if stddev <= 0.0 {
+            return Err(ArgumentError::with_message("stddev must be positive").into());
+        }
+
+        if let Some(cached) = self.cached_gauss.take() {
+            return Ok(mean + stddev * cached);
+        }
+
+        let (u1, u2) = (self.next_f64_open_closed(), self.next_f64_open_closed());
+        let r = (-2.0 * u1.ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * u2;
+        self.cached_gauss = Some(r * theta.sin());
+        Ok(mean + stddev * r * theta.cos())
+    }
+
+    /// Draw from the exponential distribution with rate `lambda`, via
+    /// inverse-CDF sampling.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ArgumentError`] if `lambda` is not positive.
+    pub fn exponential(&mut self, lambda: f64) -> Result<f64, Error> {
+        if lambda <= 0.0 {
+            return Err(ArgumentError::with_message("lambda must be positive").into());
+        }
+
+        let u = self.next_f64();
+        Ok(-(1.0 - u).ln() / lambda)
+    }
+
+    /// Draw from the gamma distribution with the given `shape` and `scale`,
+    /// via the Marsaglia–Tsang method.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ArgumentError`] if `shape` is not positive.
+    pub fn gamma(&mut self, shape: f64, scale: f64) -> Result<f64, Error> {
+        if shape <= 0.0 {
+            return Err(ArgumentError::with_message("shape must be positive").into());
+        }
+
+        if shape < 1.0 {
+            let boost = self.gamma(shape + 1.0, 1.0)?;
+            let u = self.next_f64();
+            return Ok(boost * u.powf(1.0 / shape) * scale);
+        }
+
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+
+        loop {
+            let (x, v) = loop {
+                let x = self.normal(0.0, 1.0)?;
+                let v = (1.0 + c * x).powi(3);
+                if v > 0.0 {
+                    break (x, v);
+                }
+            };
+
+            let u = self.next_f64();
+            if u.ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+                return Ok(d * v * scale);
+            }
+        }
+    }
+}
+
+impl PartialEq for Random {
+    fn eq(&self, other: &Self) -> bool {
+        self.seed == other.seed && self.algorithm == other.algorithm
+    }
+}
+
+impl Eq for Random {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Algorithm, Random};
+
+    #[test]
+    fn isaac_backend_is_deterministic_for_a_fixed_seed() {
+        let mut a = Random::with_seed_and_algorithm(1234, Algorithm::Isaac);
+        let mut b = Random::with_seed_and_algorithm(1234, Algorithm::Isaac);
+
+        let mut a_buf = [0; 64];
+        let mut b_buf = [0; 64];
+        a.bytes(&mut a_buf);
+        b.bytes(&mut b_buf);
+
+        assert_eq!(a_buf, b_buf);
+    }
+
+    #[test]
+    fn randoms_with_different_algorithms_are_unequal() {
+        let mt = Random::with_seed_and_algorithm(1234, Algorithm::MersenneTwister);
+        let isaac = Random::with_seed_and_algorithm(1234, Algorithm::Isaac);
+
+        assert_ne!(mt, isaac);
+    }
+
+    #[test]
+    fn shuffle_is_deterministic_for_a_fixed_seed() {
+        let mut a = (0..10).collect::<Vec<_>>();
+        let mut b = (0..10).collect::<Vec<_>>();
+
+        Random::with_seed(1234).shuffle(&mut a);
+        Random::with_seed(1234).shuffle(&mut b);
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn shuffle_preserves_elements() {
+        let mut items = (0..20).collect::<Vec<_>>();
+        Random::with_seed(42).shuffle(&mut items);
+
+        let mut sorted = items.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn partial_shuffle_samples_n_elements_deterministically() {
+        let mut a = (0..10).collect::<Vec<_>>();
+        let mut b = (0..10).collect::<Vec<_>>();
+
+        Random::with_seed(99).partial_shuffle(&mut a, 3);
+        Random::with_seed(99).partial_shuffle(&mut b, 3);
+
+        assert_eq!(a[..3], b[..3]);
+    }
+}
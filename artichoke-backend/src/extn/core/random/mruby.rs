@@ -11,11 +11,24 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_self_method("new_seed", random_self_new_seed, sys::mrb_args_req(1))?
         .add_self_method("srand", random_self_srand, sys::mrb_args_opt(1))?
         .add_self_method("urandom", random_self_urandom, sys::mrb_args_req(1))?
-        .add_method("initialize", random_initialize, sys::mrb_args_opt(1))?
+        .add_method("initialize", random_initialize, sys::mrb_args_opt(3))?
         .add_method("==", random_eq, sys::mrb_args_opt(1))?
         .add_method("bytes", random_bytes, sys::mrb_args_req(1))?
         .add_method("rand", random_rand, sys::mrb_args_opt(1))?
         .add_method("seed", random_seed, sys::mrb_args_none())?
+        .add_method("normal", random_normal, sys::mrb_args_opt(2))?
+        .add_method("exponential", random_exponential, sys::mrb_args_opt(1))?
+        .add_method("gamma", random_gamma, sys::mrb_args_req_and_opt(1, 1))?
+        .add_method(
+            "__shuffle_array__",
+            random_shuffle_array,
+            sys::mrb_args_req(1),
+        )?
+        .add_method(
+            "__sample_array__",
+            random_sample_array,
+            sys::mrb_args_req_and_opt(1, 1),
+        )?
         .define()?;
     interp.def_class::<random::Random>(spec)?;
 
@@ -32,12 +45,14 @@ unsafe extern "C" fn random_initialize(
     mrb: *mut sys::mrb_state,
     slf: sys::mrb_value,
 ) -> sys::mrb_value {
-    let seed = mrb_get_args!(mrb, optional = 1);
+    let (seed, reseed_after, algorithm) = mrb_get_args!(mrb, optional = 3);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
     let slf = Value::from(slf);
     let seed = seed.map(Value::from);
-    let result = trampoline::initialize(&mut guard, seed, slf);
+    let reseed_after = reseed_after.map(Value::from);
+    let algorithm = algorithm.map(Value::from);
+    let result = trampoline::initialize(&mut guard, seed, reseed_after, algorithm, slf);
     match result {
         Ok(value) => value.inner(),
         Err(exception) => exception::raise(guard, exception),
@@ -95,6 +110,86 @@ unsafe extern "C" fn random_seed(mrb: *mut sys::mrb_state, slf: sys::mrb_value)
     }
 }
 
+unsafe extern "C" fn random_normal(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (mean, stddev) = mrb_get_args!(mrb, optional = 2);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let rand = Value::from(slf);
+    let mean = mean.map(Value::from);
+    let stddev = stddev.map(Value::from);
+    let result = trampoline::normal(&mut guard, rand, mean, stddev);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn random_exponential(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let lambda = mrb_get_args!(mrb, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let rand = Value::from(slf);
+    let lambda = lambda.map(Value::from);
+    let result = trampoline::exponential(&mut guard, rand, lambda);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn random_gamma(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let (shape, scale) = mrb_get_args!(mrb, required = 1, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let rand = Value::from(slf);
+    let shape = Value::from(shape);
+    let scale = scale.map(Value::from);
+    let result = trampoline::gamma(&mut guard, rand, shape, scale);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn random_shuffle_array(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let ary = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let rand = Value::from(slf);
+    let ary = Value::from(ary);
+    let result = trampoline::shuffle_array(&mut guard, rand, ary);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn random_sample_array(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (ary, n) = mrb_get_args!(mrb, required = 1, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let rand = Value::from(slf);
+    let ary = Value::from(ary);
+    let n = n.map(Value::from);
+    let result = trampoline::sample_array(&mut guard, rand, ary, n);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn random_self_new_seed(
     mrb: *mut sys::mrb_state,
     _slf: sys::mrb_value,
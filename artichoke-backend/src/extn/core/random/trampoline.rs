@@ -0,0 +1,287 @@
+use crate::convert::BoxUnboxVmValue;
+use crate::extn::core::random::{Algorithm, Random};
+use crate::extn::prelude::*;
+
+pub fn initialize(
+    interp: &mut Artichoke,
+    seed: Option<Value>,
+    reseed_after: Option<Value>,
+    algorithm: Option<Value>,
+    into: Value,
+) -> Result<Value, Error> {
+    let seed = match seed {
+        Some(seed) => match interp.try_convert_mut::<_, Vec<u8>>(seed) {
+            Ok(bytes) if bytes.is_empty() => {
+                return Err(ArgumentError::with_message(
+                    "Random.new requires non-empty entropy source",
+                )
+                .into());
+            }
+            Ok(bytes) => fold_seed_bytes(&bytes),
+            Err(_) => interp.try_convert_mut(seed)?,
+        },
+        None => Random::new_seed(),
+    };
+
+    let algorithm = algorithm.filter(|value| !value.is_nil());
+    let algorithm = match algorithm {
+        Some(algorithm) => {
+            let name: Vec<u8> = interp.try_convert_mut(algorithm)?;
+            match &*name {
+                b"isaac" => Algorithm::Isaac,
+                b"mt" | b"mersenne_twister" => Algorithm::MersenneTwister,
+                _ => return Err(ArgumentError::with_message("unknown Random algorithm").into()),
+            }
+        }
+        None => Algorithm::default(),
+    };
+
+    let reseed_after = reseed_after.filter(|value| !value.is_nil());
+    let random = match reseed_after {
+        Some(reseed_after) => {
+            let reseed_after = interp.try_convert_mut(reseed_after)?;
+            Random::with_seed_algorithm_and_reseed_after(seed, algorithm, reseed_after)
+        }
+        None => Random::with_seed_and_algorithm(seed, algorithm),
+    };
+    let result = Random::box_into_value(random, into, interp)?;
+    Ok(result)
+}
+
+/// Fold an arbitrary-length entropy buffer (e.g. the contents of an IO or
+/// `String` seed passed to `Random.new`) into a single deterministic `u64`
+/// seed, mixing every byte rather than truncating to a machine word.
+fn fold_seed_bytes(bytes: &[u8]) -> u64 {
+    // FNV-1a: cheap, deterministic, and every input byte perturbs the hash.
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+pub fn equal(interp: &mut Artichoke, rand: Value, other: Value) -> Result<Value, Error> {
+    let mut rand = rand;
+    let mut other = other;
+    let random = Random::unbox_from_value(&mut rand, interp)?;
+    let result = if let Ok(other) = Random::unbox_from_value(&mut other, interp) {
+        *random == *other
+    } else {
+        false
+    };
+    Ok(interp.convert(result))
+}
+
+pub fn bytes(interp: &mut Artichoke, rand: Value, size: Value) -> Result<Value, Error> {
+    let mut rand = rand;
+    let size = interp.try_convert_mut(size)?;
+    let size =
+        usize::try_from(size).map_err(|_| ArgumentError::with_message("negative string size"))?;
+
+    let mut random = Random::unbox_from_value(&mut rand, interp)?;
+    let mut buf = vec![0; size];
+    // SAFETY: The `Random` is repacked before any intervening uses of
+    // `interp` which means no mruby heap allocations can occur.
+    let random_mut = random.as_inner_mut();
+    random_mut.bytes(&mut buf);
+    let inner = random.take();
+    Random::box_into_value(inner, rand, interp).expect("Random reboxing should not fail");
+
+    Ok(interp.convert_mut(buf))
+}
+
+pub fn rand(interp: &mut Artichoke, rand: Value, max: Option<Value>) -> Result<Value, Error> {
+    let mut rand = rand;
+    let mut random = Random::unbox_from_value(&mut rand, interp)?;
+    // SAFETY: The `Random` is repacked before any intervening uses of
+    // `interp` which means no mruby heap allocations can occur.
+    let random_mut = random.as_inner_mut();
+
+    let result = match max {
+        None => interp.convert_mut(random_mut.next_f64()),
+        Some(max) => {
+            let max: i64 = interp.try_convert_mut(max)?;
+            if max <= 0 {
+                let inner = random.take();
+                Random::box_into_value(inner, rand, interp)
+                    .expect("Random reboxing should not fail");
+                return Err(ArgumentError::with_message("invalid argument").into());
+            }
+            interp.convert(random_mut.next_int_in_range(max))
+        }
+    };
+
+    let inner = random.take();
+    Random::box_into_value(inner, rand, interp).expect("Random reboxing should not fail");
+    Ok(result)
+}
+
+pub fn seed(interp: &mut Artichoke, rand: Value) -> Result<Value, Error> {
+    let mut rand = rand;
+    let random = Random::unbox_from_value(&mut rand, interp)?;
+    Ok(interp.convert(random.seed()))
+}
+
+pub fn new_seed(interp: &mut Artichoke) -> Result<Value, Error> {
+    Ok(interp.convert(Random::new_seed()))
+}
+
+pub fn srand(interp: &mut Artichoke, number: Option<Value>) -> Result<Value, Error> {
+    let mut default = interp.class_constant::<Random>("DEFAULT")?;
+    let previous = Random::unbox_from_value(&mut default, interp)?.seed();
+
+    let seed = match number {
+        Some(number) => interp.try_convert_mut(number)?,
+        None => Random::new_seed(),
+    };
+    let reseeded = Random::box_into_value(Random::with_seed(seed), default, interp)?;
+    interp.define_class_constant::<Random>("DEFAULT", reseeded)?;
+
+    Ok(interp.convert(previous))
+}
+
+pub fn normal(
+    interp: &mut Artichoke,
+    rand: Value,
+    mean: Option<Value>,
+    stddev: Option<Value>,
+) -> Result<Value, Error> {
+    let mean = match mean {
+        Some(mean) => interp.try_convert_mut(mean)?,
+        None => 0.0,
+    };
+    let stddev = match stddev {
+        Some(stddev) => interp.try_convert_mut(stddev)?,
+        None => 1.0,
+    };
+
+    let mut rand = rand;
+    let mut random = Random::unbox_from_value(&mut rand, interp)?;
+    let random_mut = random.as_inner_mut();
+    let result = random_mut.normal(mean, stddev);
+    let inner = random.take();
+    Random::box_into_value(inner, rand, interp).expect("Random reboxing should not fail");
+
+    Ok(interp.convert_mut(result?))
+}
+
+pub fn exponential(
+    interp: &mut Artichoke,
+    rand: Value,
+    lambda: Option<Value>,
+) -> Result<Value, Error> {
+    let lambda = match lambda {
+        Some(lambda) => interp.try_convert_mut(lambda)?,
+        None => 1.0,
+    };
+
+    let mut rand = rand;
+    let mut random = Random::unbox_from_value(&mut rand, interp)?;
+    let random_mut = random.as_inner_mut();
+    let result = random_mut.exponential(lambda);
+    let inner = random.take();
+    Random::box_into_value(inner, rand, interp).expect("Random reboxing should not fail");
+
+    Ok(interp.convert_mut(result?))
+}
+
+pub fn gamma(
+    interp: &mut Artichoke,
+    rand: Value,
+    shape: Value,
+    scale: Option<Value>,
+) -> Result<Value, Error> {
+    let shape = interp.try_convert_mut(shape)?;
+    let scale = match scale {
+        Some(scale) => interp.try_convert_mut(scale)?,
+        None => 1.0,
+    };
+
+    let mut rand = rand;
+    let mut random = Random::unbox_from_value(&mut rand, interp)?;
+    let random_mut = random.as_inner_mut();
+    let result = random_mut.gamma(shape, scale);
+    let inner = random.take();
+    Random::box_into_value(inner, rand, interp).expect("Random reboxing should not fail");
+
+    Ok(interp.convert_mut(result?))
+}
+
+/// The primitive behind `Array#shuffle(random: rng)`: unpack `ary` into a
+/// `Vec<Value>`, shuffle it with [`Random::shuffle`], and convert the result
+/// back into a new `Array`.
+pub fn shuffle_array(interp: &mut Artichoke, rand: Value, ary: Value) -> Result<Value, Error> {
+    let mut rand = rand;
+    let mut items: Vec<Value> = interp.try_convert_mut(ary)?;
+
+    let mut random = Random::unbox_from_value(&mut rand, interp)?;
+    let random_mut = random.as_inner_mut();
+    random_mut.shuffle(&mut items);
+    let inner = random.take();
+    Random::box_into_value(inner, rand, interp).expect("Random reboxing should not fail");
+
+    Ok(interp.convert_mut(items))
+}
+
+/// The primitive behind `Array#sample(n = nil, random: rng)`: with no `n`,
+/// draw a single uniformly random element (or `nil` from an empty `ary`);
+/// with `n`, draw `n.min(ary.length)` elements without replacement via
+/// [`Random::partial_shuffle`], preserving their relative shuffle order.
+///
+/// # Errors
+///
+/// Returns an [`ArgumentError`] if `n` is negative.
+pub fn sample_array(
+    interp: &mut Artichoke,
+    rand: Value,
+    ary: Value,
+    n: Option<Value>,
+) -> Result<Value, Error> {
+    let mut rand = rand;
+    let mut items: Vec<Value> = interp.try_convert_mut(ary)?;
+
+    let n = match n {
+        Some(n) => {
+            let n: i64 = interp.try_convert_mut(n)?;
+            let n = usize::try_from(n)
+                .map_err(|_| ArgumentError::with_message("negative sample size"))?;
+            Some(n)
+        }
+        None => None,
+    };
+
+    let mut random = Random::unbox_from_value(&mut rand, interp)?;
+    let random_mut = random.as_inner_mut();
+
+    let result = match n {
+        None if items.is_empty() => Value::nil(),
+        None => {
+            random_mut.partial_shuffle(&mut items, 1);
+            items.swap_remove(0)
+        }
+        Some(n) => {
+            random_mut.partial_shuffle(&mut items, n);
+            items.truncate(n.min(items.len()));
+            interp.convert_mut(items)
+        }
+    };
+
+    let inner = random.take();
+    Random::box_into_value(inner, rand, interp).expect("Random reboxing should not fail");
+
+    Ok(result)
+}
+
+pub fn urandom(interp: &mut Artichoke, size: Value) -> Result<Value, Error> {
+    let size = interp.try_convert_mut(size)?;
+    let size =
+        usize::try_from(size).map_err(|_| ArgumentError::with_message("negative string size"))?;
+    let mut buf = vec![0; size];
+    getrandom::getrandom(&mut buf)
+        .map_err(|_| RuntimeError::with_message("failed to read OS entropy"))?;
+    Ok(interp.convert_mut(buf))
+}
@@ -0,0 +1,142 @@
+//! `SecureRandom` provides Ruby-level access to a cryptographically secure
+//! source of randomness, backed by the same OS entropy source as
+//! [`Random::urandom`](crate::extn::core::random::trampoline::urandom).
+
+use crate::extn::prelude::*;
+
+pub mod mruby;
+pub mod trampoline;
+
+pub use mruby::init;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URLSAFE_BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const ALPHANUMERIC_ALPHABET: &[u8; 62] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Fill `buf` with bytes drawn from OS entropy, falling back across the
+/// `getrandom`-style syscall chain on `ENOSYS` as documented by the
+/// [`getrandom`] crate (`getrandom(2)` on Linux, `getentropy`/`arandom` on
+/// BSD, `RtlGenRandom` on Windows).
+///
+/// [`getrandom`]: https://docs.rs/getrandom
+pub(crate) fn fill_secure_bytes(buf: &mut [u8]) -> Result<(), Error> {
+    getrandom::getrandom(buf).map_err(|_| RuntimeError::with_message("failed to read OS entropy").into())
+}
+
+/// Draw `n` secure random bytes.
+pub(crate) fn random_bytes(n: usize) -> Result<Vec<u8>, Error> {
+    let mut buf = vec![0; n];
+    fill_secure_bytes(&mut buf)?;
+    Ok(buf)
+}
+
+/// Lowercase hex encode `n` secure random bytes.
+pub(crate) fn hex(n: usize) -> Result<String, Error> {
+    let buf = random_bytes(n)?;
+    let mut out = String::with_capacity(buf.len() * 2);
+    for byte in buf {
+        out.push_str(&format!("{byte:02x}"));
+    }
+    Ok(out)
+}
+
+fn base64_encode(buf: &[u8], alphabet: &[u8; 64], padding: bool) -> String {
+    let mut out = String::with_capacity((buf.len() + 2) / 3 * 4);
+    for chunk in buf.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(alphabet[usize::from(b0 >> 2)] as char);
+        out.push(alphabet[usize::from(((b0 & 0x03) << 4) | (b1 >> 4))] as char);
+        if chunk.len() > 1 {
+            out.push(alphabet[usize::from(((b1 & 0x0f) << 2) | (b2 >> 6))] as char);
+        } else if padding {
+            out.push('=');
+        }
+        if chunk.len() > 2 {
+            out.push(alphabet[usize::from(b2 & 0x3f)] as char);
+        } else if padding {
+            out.push('=');
+        }
+    }
+    out
+}
+
+/// Standard Base64-encode `n` secure random bytes.
+pub(crate) fn base64(n: usize) -> Result<String, Error> {
+    let buf = random_bytes(n)?;
+    Ok(base64_encode(&buf, BASE64_ALPHABET, true))
+}
+
+/// URL-safe Base64-encode `n` secure random bytes.
+pub(crate) fn urlsafe_base64(n: usize, padding: bool) -> Result<String, Error> {
+    let buf = random_bytes(n)?;
+    Ok(base64_encode(&buf, URLSAFE_BASE64_ALPHABET, padding))
+}
+
+/// Draw a secure random alphanumeric `String` of length `n`.
+pub(crate) fn alphanumeric(n: usize) -> Result<String, Error> {
+    let mut out = String::with_capacity(n);
+    let mut buf = [0_u8; 1];
+    while out.len() < n {
+        fill_secure_bytes(&mut buf)?;
+        // Rejection sampling: `ALPHANUMERIC_ALPHABET` has 62 entries, so
+        // reject the `256 - (256 % 62)..256` remainder zone to avoid modulo
+        // bias.
+        let zone = 256 - (256 % ALPHANUMERIC_ALPHABET.len());
+        if usize::from(buf[0]) < zone {
+            out.push(ALPHANUMERIC_ALPHABET[usize::from(buf[0]) % ALPHANUMERIC_ALPHABET.len()] as char);
+        }
+    }
+    Ok(out)
+}
+
+/// Draw a secure random `f64` in `[0, 1)`.
+pub(crate) fn random_float() -> Result<f64, Error> {
+    let mut buf = [0; 8];
+    fill_secure_bytes(&mut buf)?;
+    let bits = u64::from_ne_bytes(buf) >> 11;
+    Ok((bits as f64) * (1.0 / (1_u64 << 53) as f64))
+}
+
+/// Draw a secure random, unbiased `u64` in `[0, max)`.
+pub(crate) fn random_below(max: u64) -> Result<u64, Error> {
+    if max == 0 {
+        return Ok(0);
+    }
+    let zone = u64::MAX - (u64::MAX % max);
+    loop {
+        let mut buf = [0; 8];
+        fill_secure_bytes(&mut buf)?;
+        let value = u64::from_ne_bytes(buf);
+        if value < zone {
+            return Ok(value % max);
+        }
+    }
+}
+
+/// Generate an RFC 4122 version 4 UUID, formatted as
+/// `xxxxxxxx-xxxx-4xxx-yxxx-xxxxxxxxxxxx`.
+pub(crate) fn uuid() -> Result<String, Error> {
+    let mut bytes = [0_u8; 16];
+    fill_secure_bytes(&mut bytes)?;
+
+    // Set the version nibble to 4.
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    // Set the variant bits to `10xxxxxx`.
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    Ok(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
@@ -0,0 +1,108 @@
+use crate::extn::core::securerandom::trampoline;
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_module_defined::<SecureRandom>() {
+        return Ok(());
+    }
+    let spec = module::Spec::new("SecureRandom", None)?;
+    module::Builder::for_spec(interp, &spec)
+        .add_self_method("hex", securerandom_hex, sys::mrb_args_opt(1))?
+        .add_self_method("base64", securerandom_base64, sys::mrb_args_opt(1))?
+        .add_self_method("urlsafe_base64", securerandom_urlsafe_base64, sys::mrb_args_opt(2))?
+        .add_self_method("random_bytes", securerandom_random_bytes, sys::mrb_args_opt(1))?
+        .add_self_method("random_number", securerandom_random_number, sys::mrb_args_opt(1))?
+        .add_self_method("alphanumeric", securerandom_alphanumeric, sys::mrb_args_opt(1))?
+        .add_self_method("uuid", securerandom_uuid, sys::mrb_args_none())?
+        .define()?;
+    interp.def_module::<SecureRandom>(spec)?;
+    let _ = interp.eval(&include_bytes!("securerandom.rb")[..])?;
+    trace!("Patched SecureRandom onto interpreter");
+    Ok(())
+}
+
+pub struct SecureRandom;
+
+unsafe extern "C" fn securerandom_hex(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let n = mrb_get_args!(mrb, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let n = n.map(Value::from);
+    let result = trampoline::hex_trampoline(&mut guard, n);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn securerandom_base64(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let n = mrb_get_args!(mrb, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let n = n.map(Value::from);
+    let result = trampoline::base64_trampoline(&mut guard, n);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn securerandom_urlsafe_base64(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let (n, padding) = mrb_get_args!(mrb, optional = 2);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let n = n.map(Value::from);
+    let padding = padding.map(Value::from);
+    let result = trampoline::urlsafe_base64_trampoline(&mut guard, n, padding);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn securerandom_random_bytes(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let n = mrb_get_args!(mrb, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let n = n.map(Value::from);
+    let result = trampoline::random_bytes(&mut guard, n);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn securerandom_random_number(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let max = mrb_get_args!(mrb, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let max = max.map(Value::from);
+    let result = trampoline::random_number(&mut guard, max);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn securerandom_alphanumeric(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let n = mrb_get_args!(mrb, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let n = n.map(Value::from);
+    let result = trampoline::alphanumeric_trampoline(&mut guard, n);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn securerandom_uuid(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let result = trampoline::uuid_trampoline(&mut guard);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
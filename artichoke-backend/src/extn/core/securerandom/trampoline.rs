@@ -0,0 +1,75 @@
+use super::{alphanumeric, base64, hex, random_below, random_float, urlsafe_base64, uuid};
+use crate::extn::core::securerandom;
+use crate::extn::prelude::*;
+
+fn size_arg(interp: &mut Artichoke, n: Option<Value>) -> Result<usize, Error> {
+    let n = match n {
+        Some(n) => interp.try_convert_mut(n)?,
+        None => 16_i64,
+    };
+    usize::try_from(n).map_err(|_| ArgumentError::with_message("negative string size").into())
+}
+
+pub fn hex_trampoline(interp: &mut Artichoke, n: Option<Value>) -> Result<Value, Error> {
+    let n = size_arg(interp, n)?;
+    let result = hex(n)?;
+    Ok(interp.convert_mut(result))
+}
+
+pub fn base64_trampoline(interp: &mut Artichoke, n: Option<Value>) -> Result<Value, Error> {
+    let n = size_arg(interp, n)?;
+    let result = base64(n)?;
+    Ok(interp.convert_mut(result))
+}
+
+pub fn urlsafe_base64_trampoline(
+    interp: &mut Artichoke,
+    n: Option<Value>,
+    padding: Option<Value>,
+) -> Result<Value, Error> {
+    let n = size_arg(interp, n)?;
+    let padding = match padding {
+        Some(padding) => interp.try_convert_mut(padding)?,
+        None => false,
+    };
+    let result = urlsafe_base64(n, padding)?;
+    Ok(interp.convert_mut(result))
+}
+
+pub fn random_bytes(interp: &mut Artichoke, n: Option<Value>) -> Result<Value, Error> {
+    let n = size_arg(interp, n)?;
+    let result = securerandom::random_bytes(n)?;
+    Ok(interp.convert_mut(result))
+}
+
+pub fn random_number(interp: &mut Artichoke, max: Option<Value>) -> Result<Value, Error> {
+    let max = match max {
+        None => return Ok(interp.convert_mut(random_float()?)),
+        Some(max) => max,
+    };
+
+    if let Ok(max) = interp.try_convert_mut::<_, i64>(max) {
+        if max <= 0 {
+            return Ok(interp.convert_mut(random_float()?));
+        }
+        let value = random_below(max as u64)?;
+        return Ok(interp.convert(value as i64));
+    }
+
+    let max: f64 = interp.try_convert_mut(max)?;
+    if max <= 0.0 {
+        return Ok(interp.convert_mut(random_float()?));
+    }
+    Ok(interp.convert_mut(random_float()? * max))
+}
+
+pub fn alphanumeric_trampoline(interp: &mut Artichoke, n: Option<Value>) -> Result<Value, Error> {
+    let n = size_arg(interp, n)?;
+    let result = alphanumeric(n)?;
+    Ok(interp.convert_mut(result))
+}
+
+pub fn uuid_trampoline(interp: &mut Artichoke) -> Result<Value, Error> {
+    let result = uuid()?;
+    Ok(interp.convert_mut(result))
+}
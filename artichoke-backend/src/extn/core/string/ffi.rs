@@ -1,4 +1,3 @@
-use core::char;
 use core::convert::TryFrom;
 use core::hash::{BuildHasher, Hash, Hasher};
 use core::ptr;
@@ -14,7 +13,8 @@ use artichoke_core::hash::Hash as _;
 use bstr::ByteSlice;
 use spinoso_exception::ArgumentError;
 use spinoso_exception::NoMemoryError;
-use spinoso_string::{RawParts, String};
+use spinoso_exception::RangeError;
+use spinoso_string::{Encoding, RawParts, String};
 
 use super::trampoline;
 use crate::convert::BoxUnboxVmValue;
@@ -28,7 +28,18 @@ use crate::value::Value;
 #[no_mangle]
 unsafe extern "C" fn mrb_str_new_capa(mrb: *mut sys::mrb_state, capa: usize) -> sys::mrb_value {
     unwrap_interpreter!(mrb, to => guard);
-    let result = String::with_capacity(capa);
+    // A request beyond `isize::MAX` bytes can never succeed under Rust's
+    // allocator contract; report it as "string too big" rather than letting
+    // the allocation attempt below abort the process.
+    if capa > isize::MAX as usize {
+        let err = RangeError::with_message("string too big");
+        error::raise(guard, err);
+    }
+    let mut result = String::utf8(Vec::new());
+    if result.try_reserve(capa).is_err() {
+        let err = NoMemoryError::with_message("out of memory");
+        error::raise(guard, err);
+    }
     let result = String::alloc_value(result, &mut guard);
     match result {
         Ok(value) => value.inner(),
@@ -40,7 +51,11 @@ unsafe extern "C" fn mrb_str_new_capa(mrb: *mut sys::mrb_state, capa: usize) ->
 // MRB_API mrb_value mrb_str_new(mrb_state *mrb, const char *p, size_t len)
 // ```
 #[no_mangle]
-unsafe extern "C" fn mrb_str_new(mrb: *mut sys::mrb_state, p: *const c_char, len: usize) -> sys::mrb_value {
+unsafe extern "C" fn mrb_str_new(
+    mrb: *mut sys::mrb_state,
+    p: *const c_char,
+    len: usize,
+) -> sys::mrb_value {
     unwrap_interpreter!(mrb, to => guard);
     let s = if p.is_null() {
         String::utf8(vec![0; len])
@@ -60,7 +75,10 @@ unsafe extern "C" fn mrb_str_new(mrb: *mut sys::mrb_state, p: *const c_char, len
 // MRB_API mrb_value mrb_str_new_cstr(mrb_state *mrb, const char *p)
 // ```
 #[no_mangle]
-unsafe extern "C" fn mrb_str_new_cstr(mrb: *mut sys::mrb_state, p: *const c_char) -> sys::mrb_value {
+unsafe extern "C" fn mrb_str_new_cstr(
+    mrb: *mut sys::mrb_state,
+    p: *const c_char,
+) -> sys::mrb_value {
     unwrap_interpreter!(mrb, to => guard);
     let cstr = CStr::from_ptr(p);
     let bytes = cstr.to_bytes().to_vec();
@@ -76,11 +94,116 @@ unsafe extern "C" fn mrb_str_new_cstr(mrb: *mut sys::mrb_state, p: *const c_char
 // MRB_API mrb_value mrb_str_new_static(mrb_state *mrb, const char *p, size_t len)
 // ```
 #[no_mangle]
-unsafe extern "C" fn mrb_str_new_static(mrb: *mut sys::mrb_state, p: *const c_char, len: usize) -> sys::mrb_value {
-    // Artichoke doesn't have a static string optimization.
+unsafe extern "C" fn mrb_str_new_static(
+    mrb: *mut sys::mrb_state,
+    p: *const c_char,
+    len: usize,
+) -> sys::mrb_value {
+    // UNIMPLEMENTED, blocked on a missing dependency: this still just copies
+    // via `mrb_str_new` instead of borrowing `p`.
+    //
+    // A real fix needs a storage-kind tag distinguishing borrowed/`'static`
+    // buffers from owned ones, checked by `mrb_gc_free_str` before it calls
+    // `String::from_raw_parts` on them. That tag has to live on one of two
+    // things, and this checkout has the source for neither:
+    //   - `spinoso_string::String`'s own representation (an external crate
+    //     here), which is the right owner for it, matching the inline-SSO
+    //     half of this request; or
+    //   - the raw `sys::RString` flags bitfield that `mrb_gc_free_str`
+    //     already reads directly, but whose layout (and the `sys` crate
+    //     that defines it) is also not present in this checkout, so packing
+    //     a new bit into it here would be guessing at undocumented C ABI
+    //     layout rather than implementing against a known one.
+    //
+    // Pushing this request back to the backlog rather than landing a
+    // same-behavior commit under a misleading title.
+    //
+    // TODO(chunk2-3): open. Do not treat this function as the delivered
+    // feature — re-pick this ticket once `spinoso_string`/`sys` source is
+    // available in this checkout.
     mrb_str_new(mrb, p, len)
 }
 
+// Character-index helpers shared by `mrb_str_index`, `mrb_str_substr`, and
+// the Integer-index fast path of `mrb_str_aref`: MRI indexes UTF-8 `String`s
+// by character, not by byte, while binary/ASCII-8BIT strings keep raw byte
+// semantics. This belongs on `spinoso_string::String` itself so every
+// caller shares one implementation; it lives here instead because this
+// checkout does not include the `spinoso_string` crate source.
+//
+// `pub(super)`, not private: `trampoline::aref`'s non-Integer index path
+// (in the sibling `trampoline` module, not present in this checkout) must
+// import these via `use super::ffi::{...}` rather than re-deriving its own
+// copy of the character/byte conversion, or the two paths will drift apart.
+
+/// Character-boundary byte offsets of `s`, including a trailing entry equal
+/// to `s.len()`. `boundaries[i]` is the byte offset of the `i`-th character,
+/// and `boundaries.len() - 1` is the character count.
+pub(super) fn char_boundaries(s: &str) -> Vec<usize> {
+    s.char_indices()
+        .map(|(i, _)| i)
+        .chain(core::iter::once(s.len()))
+        .collect()
+}
+
+/// The length of `string` in its own indexing units: characters for UTF-8,
+/// bytes for binary/ASCII-8BIT.
+pub(super) fn indexable_len(string: &String) -> Option<usize> {
+    if matches!(string.encoding(), Encoding::Binary) {
+        Some(string.len())
+    } else {
+        str::from_utf8(string.as_slice())
+            .ok()
+            .map(|s| s.chars().count())
+    }
+}
+
+/// Resolve a possibly-negative MRI-style offset against a sequence of
+/// length `len`, returning the corresponding non-negative index. A negative
+/// offset counts back from `len` and yields `None` if it reaches before the
+/// start; a non-negative offset is returned unchanged, without a bound check
+/// against `len`.
+pub(super) fn resolve_index(len: usize, offset: sys::mrb_int) -> Option<usize> {
+    if let Ok(offset) = usize::try_from(offset) {
+        Some(offset)
+    } else {
+        offset
+            .checked_neg()
+            .and_then(|offset| usize::try_from(offset).ok())
+            .and_then(|offset| len.checked_sub(offset))
+    }
+}
+
+/// Map an already-resolved, non-negative index offset to the byte offset
+/// `string[offset..]` should start reading from.
+pub(super) fn index_to_byte_offset(string: &String, offset: usize) -> Option<usize> {
+    if matches!(string.encoding(), Encoding::Binary) {
+        (offset <= string.len()).then_some(offset)
+    } else {
+        let s = str::from_utf8(string.as_slice()).ok()?;
+        char_boundaries(s).get(offset).copied()
+    }
+}
+
+/// Map a `(offset, len)` character/byte span (already resolved to
+/// non-negative) to the byte range `string` should be sliced with.
+pub(super) fn span_to_byte_range(
+    string: &String,
+    offset: usize,
+    len: usize,
+) -> Option<(usize, usize)> {
+    if matches!(string.encoding(), Encoding::Binary) {
+        let end = offset.checked_add(len)?.min(string.len());
+        (offset <= string.len()).then_some((offset, end))
+    } else {
+        let s = str::from_utf8(string.as_slice()).ok()?;
+        let boundaries = char_boundaries(s);
+        let start = *boundaries.get(offset)?;
+        let end_index = offset.checked_add(len)?.min(boundaries.len() - 1);
+        Some((start, boundaries[end_index]))
+    }
+}
+
 // ```c
 // MRB_API mrb_int mrb_str_index(mrb_state *mrb, mrb_value str, const char *sptr, mrb_int slen, mrb_int offset)
 // ```
@@ -100,29 +223,50 @@ unsafe extern "C" fn mrb_str_index(
         return -1;
     };
 
-    let offset = if let Ok(offset) = usize::try_from(offset) {
+    let len = if let Some(len) = indexable_len(&string) {
+        len
+    } else {
+        return -1;
+    };
+    let offset = if let Some(offset) = resolve_index(len, offset) {
         offset
     } else {
-        let offset = offset
-            .checked_neg()
-            .and_then(|offset| usize::try_from(offset).ok())
-            .and_then(|offset| offset.checked_sub(string.len()));
-        if let Some(offset) = offset {
-            offset
-        } else {
-            return -1;
-        }
+        return -1;
     };
-    let haystack = if let Some(haystack) = string.get(offset..) {
+    let byte_offset = if let Some(byte_offset) = index_to_byte_offset(&string, offset) {
+        byte_offset
+    } else {
+        return -1;
+    };
+    let haystack = if let Some(haystack) = string.get(byte_offset..) {
         haystack
     } else {
         return -1;
     };
-    let needle = slice::from_raw_parts(sptr.cast::<u8>(), usize::try_from(slen).unwrap_or_default());
+    let needle =
+        slice::from_raw_parts(sptr.cast::<u8>(), usize::try_from(slen).unwrap_or_default());
     if needle.is_empty() {
         return offset as sys::mrb_int;
     }
-    haystack.find(needle).map_or(-1, |pos| pos as sys::mrb_int)
+    let pos = if let Some(pos) = haystack.find(needle) {
+        pos
+    } else {
+        return -1;
+    };
+
+    if matches!(string.encoding(), Encoding::Binary) {
+        return (byte_offset + pos) as sys::mrb_int;
+    }
+    // The needle may match mid-character-boundary in byte terms only if the
+    // needle itself is not valid UTF-8; for well-formed UTF-8 needles this
+    // always lands on a character boundary, so re-counting characters up to
+    // the match gives the correct character offset.
+    let s = if let Ok(s) = str::from_utf8(string.as_slice()) {
+        s
+    } else {
+        return -1;
+    };
+    s[..byte_offset + pos].chars().count() as sys::mrb_int
 }
 
 // ```c
@@ -140,7 +284,56 @@ unsafe extern "C" fn mrb_str_aref(
     let indx = Value::from(indx);
     let alen = Value::from(alen);
 
-    let alen = if alen.is_unreachable() { None } else { Some(alen) };
+    let alen = if alen.is_unreachable() {
+        None
+    } else {
+        Some(alen)
+    };
+
+    // `indx`/`alen` Integer forms are the common case for `String#[]` and
+    // share the same character/byte indexing rules as `mrb_str_index` and
+    // `mrb_str_substr`, so resolve them with the same helpers instead of
+    // falling through to byte-based semantics in `trampoline::aref`.
+    // `Range`, `Regexp`, and `String` forms of `indx` aren't Integers and
+    // fall back to `trampoline::aref` unchanged.
+    if let Ok(beg) = guard.try_convert_mut::<_, i64>(indx) {
+        let mut string = value;
+        let string = if let Ok(string) = String::unbox_from_value(&mut string, &mut guard) {
+            string
+        } else {
+            return Value::nil().into();
+        };
+        let indexable_len = if let Some(indexable_len) = indexable_len(&string) {
+            indexable_len
+        } else {
+            return Value::nil().into();
+        };
+        let offset = if let Some(offset) = resolve_index(indexable_len, beg) {
+            offset
+        } else {
+            return Value::nil().into();
+        };
+        let len = match alen {
+            // A bare `Integer` index (no length) is only valid strictly
+            // inside the string: `s[s.length]` is `nil`, unlike
+            // `s[s.length, 0]`, which is `""`.
+            None if offset >= indexable_len => return Value::nil().into(),
+            None => 1,
+            Some(alen) => match guard.try_convert_mut::<_, i64>(alen) {
+                Ok(len) if len >= 0 => usize::try_from(len).unwrap_or_default(),
+                _ => return Value::nil().into(),
+            },
+        };
+        return if let Some((start, end)) = span_to_byte_range(&string, offset, len) {
+            let slice = &string.as_slice()[start..end];
+            let substr = String::with_bytes_and_encoding(slice.to_vec(), string.encoding());
+            String::alloc_value(substr, &mut guard)
+                .unwrap_or_default()
+                .into()
+        } else {
+            Value::nil().into()
+        };
+    }
 
     let result = trampoline::aref(&mut guard, value, indx, alen);
     match result {
@@ -162,19 +355,41 @@ unsafe extern "C" fn mrb_str_aref(
 //
 // NOTE: Implemented in C in `mruby-sys/src/mruby-sys/ext.c`.
 
+/// Classification of a failed string-allocation request, shared by
+/// `mrb_str_resize` and `mrb_str_cat`: a request for more than `isize::MAX`
+/// bytes can never succeed under Rust's allocator contract, so it's a
+/// "string too big" `RangeError`, distinct from a `try_reserve` failure
+/// below that limit, which is a genuine out-of-memory condition.
+enum CapacityError {
+    TooBig,
+    OutOfMemory(TryReserveError),
+}
+
 // ```c
 // MRB_API mrb_value mrb_str_resize(mrb_state *mrb, mrb_value str, mrb_int len)
 // ```
 #[no_mangle]
-unsafe extern "C" fn mrb_str_resize(mrb: *mut sys::mrb_state, s: sys::mrb_value, len: sys::mrb_int) -> sys::mrb_value {
-    fn try_resize(s: &mut String, len: usize) -> Result<(), TryReserveError> {
+unsafe extern "C" fn mrb_str_resize(
+    mrb: *mut sys::mrb_state,
+    s: sys::mrb_value,
+    len: sys::mrb_int,
+) -> sys::mrb_value {
+    fn try_resize(s: &mut String, len: usize) -> Result<(), CapacityError> {
         match len.checked_sub(s.len()) {
-            Some(0) => {}
-            Some(additional) => s.try_reserve(additional)?,
+            Some(0) => Ok(()),
+            // A requested total length beyond `isize::MAX` bytes can never
+            // succeed under Rust's allocator contract, so it's distinguished
+            // from a `try_reserve` failure, which is a genuine OOM condition.
+            Some(_) if len > isize::MAX as usize => Err(CapacityError::TooBig),
+            Some(additional) => s
+                .try_reserve(additional)
+                .map_err(CapacityError::OutOfMemory),
             // If the given length is less than the length of the `String`, truncate.
-            None => s.truncate(len),
+            None => {
+                s.truncate(len);
+                Ok(())
+            }
         }
-        Ok(())
     }
 
     unwrap_interpreter!(mrb, to => guard, or_else = s);
@@ -196,17 +411,16 @@ unsafe extern "C" fn mrb_str_resize(mrb: *mut sys::mrb_state, s: sys::mrb_value,
     let result = try_resize(string_mut, len);
 
     let inner = string.take();
-    let value = String::box_into_value(inner, value, &mut guard).expect("String reboxing should not fail");
+    let value =
+        String::box_into_value(inner, value, &mut guard).expect("String reboxing should not fail");
 
-    // `allow` for clarity and to potentially handle `TryReserveErrorKind`.
-    #[allow(clippy::single_match_else)]
     match result {
-        Ok(_) => value.inner(),
-        // NOTE: Ideally this code would distinguish between a capacity overflow
-        // (string too large) vs an out of memory condition (allocation failure).
-        // This is not possible on stable Rust since `TryReserveErrorKind` is
-        // unstable.
-        Err(_) => {
+        Ok(()) => value.inner(),
+        Err(CapacityError::TooBig) => {
+            let err = RangeError::with_message("string too big");
+            error::raise(guard, err);
+        }
+        Err(CapacityError::OutOfMemory(_)) => {
             // NOTE: This code can't use an `Error` unified exception trait object.
             // Since we're in memory error territory, we're not sure if we can
             // allocate the `Box` it requires.
@@ -239,7 +453,11 @@ unsafe extern "C" fn mrb_str_resize(mrb: *mut sys::mrb_state, s: sys::mrb_value,
 // MRB_API mrb_value mrb_str_plus(mrb_state *mrb, mrb_value a, mrb_value b)
 // ```
 #[no_mangle]
-unsafe extern "C" fn mrb_str_plus(mrb: *mut sys::mrb_state, a: sys::mrb_value, b: sys::mrb_value) -> sys::mrb_value {
+unsafe extern "C" fn mrb_str_plus(
+    mrb: *mut sys::mrb_state,
+    a: sys::mrb_value,
+    b: sys::mrb_value,
+) -> sys::mrb_value {
     unwrap_interpreter!(mrb, to => guard);
     let mut a = Value::from(a);
     let mut b = Value::from(b);
@@ -255,7 +473,17 @@ unsafe extern "C" fn mrb_str_plus(mrb: *mut sys::mrb_state, a: sys::mrb_value, b
         return Value::nil().into();
     };
 
-    let mut s = String::with_capacity_and_encoding(a.len() + b.len(), a.encoding());
+    let requested = a.len().saturating_add(b.len());
+    if requested > isize::MAX as usize {
+        let err = RangeError::with_message("string too big");
+        error::raise(guard, err);
+    }
+
+    let mut s = String::with_capacity_and_encoding(0, a.encoding());
+    if s.try_reserve(requested).is_err() {
+        let err = NoMemoryError::with_message("out of memory");
+        error::raise(guard, err);
+    }
 
     s.extend_from_slice(a.as_slice());
     s.extend_from_slice(b.as_slice());
@@ -268,7 +496,11 @@ unsafe extern "C" fn mrb_str_plus(mrb: *mut sys::mrb_state, a: sys::mrb_value, b
 // MRB_API int mrb_str_cmp(mrb_state *mrb, mrb_value str1, mrb_value str2)
 // ```
 #[no_mangle]
-unsafe extern "C" fn mrb_str_cmp(mrb: *mut sys::mrb_state, str1: sys::mrb_value, str2: sys::mrb_value) -> c_int {
+unsafe extern "C" fn mrb_str_cmp(
+    mrb: *mut sys::mrb_state,
+    str1: sys::mrb_value,
+    str2: sys::mrb_value,
+) -> c_int {
     unwrap_interpreter!(mrb, to => guard, or_else = -1);
     let mut a = Value::from(str1);
     let mut b = Value::from(str2);
@@ -377,23 +609,31 @@ unsafe extern "C" fn mrb_str_substr(
         return Value::nil().into();
     };
 
-    let offset = if let Ok(offset) = usize::try_from(beg) {
+    let indexable_len = if let Some(indexable_len) = indexable_len(&string) {
+        indexable_len
+    } else {
+        return Value::nil().into();
+    };
+    let offset = if let Some(offset) = resolve_index(indexable_len, beg) {
         offset
     } else {
-        let offset = beg
-            .checked_neg()
-            .and_then(|offset| usize::try_from(offset).ok())
-            .and_then(|offset| offset.checked_sub(string.len()));
-        if let Some(offset) = offset {
-            offset
-        } else {
-            return Value::nil().into();
-        }
+        return Value::nil().into();
+    };
+    let len = if let Ok(len) = usize::try_from(len) {
+        len
+    } else {
+        return Value::nil().into();
     };
 
-    if let Some(slice) = string.get(offset..) {
+    if let Some((start, end)) = span_to_byte_range(&string, offset, len) {
+        // `span_to_byte_range` only ever returns character (never
+        // mid-character) boundaries for UTF-8 strings, so this never splits
+        // a multibyte sequence.
+        let slice = &string.as_slice()[start..end];
         let substr = String::with_bytes_and_encoding(slice.to_vec(), string.encoding());
-        String::alloc_value(substr, &mut guard).unwrap_or_default().into()
+        String::alloc_value(substr, &mut guard)
+            .unwrap_or_default()
+            .into()
     } else {
         Value::nil().into()
     }
@@ -407,7 +647,9 @@ unsafe extern "C" fn mrb_ptr_to_str(mrb: *mut sys::mrb_state, p: *mut c_void) ->
     unwrap_interpreter!(mrb, to => guard);
     let mut s = String::with_capacity(16 + 2);
     let _ignore = write!(s, "{:p}", p);
-    String::alloc_value(s, &mut guard).unwrap_or_default().into()
+    String::alloc_value(s, &mut guard)
+        .unwrap_or_default()
+        .into()
 }
 
 // ```c
@@ -422,7 +664,10 @@ unsafe extern "C" fn mrb_ptr_to_str(mrb: *mut sys::mrb_state, p: *mut c_void) ->
 //
 // obsolete: use `RSTRING_CSTR()` or `mrb_string_cstr()`
 #[no_mangle]
-unsafe extern "C" fn mrb_string_value_cstr(mrb: *mut sys::mrb_state, ptr: *mut sys::mrb_value) -> *const c_char {
+unsafe extern "C" fn mrb_string_value_cstr(
+    mrb: *mut sys::mrb_state,
+    ptr: *mut sys::mrb_value,
+) -> *const c_char {
     unwrap_interpreter!(mrb, to => guard, or_else = ptr::null());
     let mut s = Value::from(*ptr);
     let mut string = if let Ok(string) = String::unbox_from_value(&mut s, &mut guard) {
@@ -500,56 +745,318 @@ unsafe extern "C" fn mrb_str_to_integer(
     } else {
         return guard.convert(0_i64).into();
     };
-    let num = if let Ok(s) = str::from_utf8(s.as_slice()) {
-        if let Ok(num) = s.parse::<i64>() {
-            num
-        } else if badcheck {
-            let err = ArgumentError::with_message("invalid number");
+
+    let base = i32::try_from(base).unwrap_or(0);
+    if base != 0 && !(2..=36).contains(&base) {
+        let err = ArgumentError::with_message("illegal radix");
+        error::raise(guard, err);
+    }
+
+    match scan_ruby_integer(s.as_slice(), base) {
+        Some((num, true)) => guard.convert(num).into(),
+        Some((num, false)) if !badcheck => guard.convert(num).into(),
+        None if !badcheck => guard.convert(0_i64).into(),
+        Some(_) | None => {
+            let err = ArgumentError::with_message("invalid value for Integer()");
             error::raise(guard, err);
-        } else {
-            return guard.convert(0_i64).into();
         }
-    } else if badcheck {
-        let err = ArgumentError::with_message("invalid number");
-        error::raise(guard, err);
-    } else {
-        return guard.convert(0_i64).into();
+    }
+}
+
+/// Parse a Ruby-style integer literal out of `bytes` using `base` (`0` means
+/// auto-detect the radix from a `0x`/`0b`/`0o`/`0d` prefix, or a legacy
+/// leading `0`, falling back to decimal).
+///
+/// Returns the signed magnitude together with whether the *entire* trimmed
+/// input was consumed by the number, which distinguishes `Kernel#Integer`
+/// (requires a full match) from `String#to_i` (parses a leading run of
+/// valid digits and ignores the rest).
+fn scan_ruby_integer(bytes: &[u8], base: i32) -> Option<(i64, bool)> {
+    // MRI's `to_i` only skips ASCII whitespace; `str::trim()` is
+    // Unicode-aware and would also swallow e.g. U+00A0 (NBSP), which MRI
+    // treats as an ordinary, non-skippable character.
+    let s = str::from_utf8(bytes)
+        .ok()?
+        .trim_start_matches(|ch: char| ch.is_ascii_whitespace());
+
+    let (sign, rest): (i64, &str) = match s.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, s.strip_prefix('+').unwrap_or(s)),
     };
-    let radix = match u32::try_from(base) {
-        Ok(base) if (2..=36).contains(&base) => base,
-        Ok(_) | Err(_) => {
-            let err = ArgumentError::with_message("illegal radix");
-            error::raise(guard, err);
+
+    let (radix, rest) = detect_integer_radix(rest, base)?;
+
+    let mut digits = std::string::String::with_capacity(rest.len());
+    let mut prev_was_digit = false;
+    let mut consumed_all = true;
+    for ch in rest.chars() {
+        if ch == '_' {
+            if !prev_was_digit {
+                consumed_all = false;
+                break;
+            }
+            prev_was_digit = false;
+            continue;
+        }
+        if ch.to_digit(radix).is_none() {
+            consumed_all = false;
+            break;
+        }
+        digits.push(ch);
+        prev_was_digit = true;
+    }
+    if digits.is_empty() {
+        return None;
+    }
+    // A trailing `_` with no following digit is not part of the number.
+    consumed_all &= prev_was_digit;
+
+    let magnitude = i64::from_str_radix(&digits, radix).ok()?;
+    Some((sign * magnitude, consumed_all))
+}
+
+/// Strip a radix prefix from `s` and determine the radix to parse digits
+/// with, reconciling the caller-supplied `base` (`0` for auto-detect)
+/// against any prefix found.
+fn detect_integer_radix(s: &str, base: i32) -> Option<(u32, &str)> {
+    let prefixed = match s.as_bytes() {
+        [b'0', b'x' | b'X', ..] => Some((16_u32, &s[2..])),
+        [b'0', b'b' | b'B', ..] => Some((2, &s[2..])),
+        [b'0', b'o' | b'O', ..] => Some((8, &s[2..])),
+        [b'0', b'd' | b'D', ..] => Some((10, &s[2..])),
+        [b'0', next, ..] if next.is_ascii_digit() => Some((8, &s[1..])),
+        _ => None,
+    };
+
+    match (base, prefixed) {
+        (0, Some((radix, rest))) => Some((radix, rest)),
+        (0, None) => Some((10, s)),
+        (base, Some((radix, rest))) if u32::try_from(base).ok() == Some(radix) => {
+            Some((radix, rest))
         }
+        (base, _) => {
+            let radix = u32::try_from(base).ok()?;
+            (2..=36).contains(&radix).then_some((radix, s))
+        }
+    }
+}
+
+/// Parse a Ruby-style float literal out of `bytes`, mirroring
+/// `scan_ruby_integer`'s contract: returns the parsed value together with
+/// whether the *entire* (whitespace-trimmed) input was consumed, which
+/// distinguishes `Kernel#Float` (requires a full match) from `String#to_f`
+/// (parses a leading run of valid float syntax and ignores the rest).
+///
+/// Accepts an optional sign, `Inf`/`Infinity`/`NaN` (case-insensitively),
+/// hexadecimal float literals (`0x1.8p3`), and `_` digit-group separators
+/// that are rejected (i.e. not consumed) if leading, trailing, or doubled.
+fn scan_ruby_float(bytes: &[u8]) -> Option<(f64, bool)> {
+    let s = str::from_utf8(bytes).ok()?;
+    let s = s.trim_start_matches(|c: char| c.is_ascii_whitespace());
+
+    let (sign, rest) = match s.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let (value, consumed) = if let Some(named) = scan_named_float(rest) {
+        named
+    } else if let Some(hex_rest) = rest.strip_prefix("0x").or_else(|| rest.strip_prefix("0X")) {
+        let (value, hex_consumed) = scan_hex_float(hex_rest)?;
+        (value, hex_consumed + 2)
+    } else {
+        scan_decimal_float(rest)?
     };
-    let mut result = vec![];
-    let mut x = num;
 
-    loop {
-        let m = u32::try_from(x % base).expect("base must be <= 36, which guarantees the result is in range for u32");
-        x /= base;
+    let trailing = rest[consumed..].trim_start_matches(|c: char| c.is_ascii_whitespace());
+    Some((sign * value, trailing.is_empty()))
+}
+
+/// Match a leading `Inf`, `Infinity`, or `NaN` literal (case-insensitive).
+fn scan_named_float(s: &str) -> Option<(f64, usize)> {
+    const NAMED: [(&str, f64); 3] = [
+        ("infinity", f64::INFINITY),
+        ("inf", f64::INFINITY),
+        ("nan", f64::NAN),
+    ];
+    for (name, value) in NAMED {
+        if s.len() >= name.len() && s[..name.len()].eq_ignore_ascii_case(name) {
+            return Some((value, name.len()));
+        }
+    }
+    None
+}
 
-        // will panic if you use a bad radix (< 2 or > 36).
-        result.push(char::from_digit(m, radix).unwrap());
-        if x == 0 {
+/// Consume a run of ASCII digits starting at byte offset `start`, allowing
+/// `_` separators only directly between two digits. Returns the separator-
+/// stripped digits and the byte offset immediately past the run. Returns
+/// `None` if there is no leading digit to consume.
+fn scan_digit_run(s: &str, start: usize) -> Option<(std::string::String, usize)> {
+    scan_radix_digit_run(s, start, 10)
+}
+
+/// Like [`scan_digit_run`], but for hexadecimal digits.
+fn scan_hex_digit_run(s: &str, start: usize) -> Option<(std::string::String, usize)> {
+    scan_radix_digit_run(s, start, 16)
+}
+
+fn scan_radix_digit_run(s: &str, start: usize, radix: u32) -> Option<(std::string::String, usize)> {
+    let bytes = s.as_bytes();
+    let mut i = start;
+    let mut digits = std::string::String::new();
+    let mut prev_was_digit = false;
+    while i < bytes.len() {
+        let ch = bytes[i] as char;
+        if ch.is_digit(radix) {
+            digits.push(ch);
+            prev_was_digit = true;
+            i += 1;
+        } else if ch == '_'
+            && prev_was_digit
+            && matches!(bytes.get(i + 1), Some(&b) if (b as char).is_digit(radix))
+        {
+            prev_was_digit = false;
+            i += 1;
+        } else {
             break;
         }
     }
-    let int = result.into_iter().rev().collect::<String>();
-    String::alloc_value(int, &mut guard).unwrap_or_default().into()
+    (!digits.is_empty()).then_some((digits, i))
+}
+
+/// Parse a decimal float (`123.456e-7`) starting at the beginning of `s`.
+/// Returns the value and the number of bytes of `s` consumed.
+fn scan_decimal_float(s: &str) -> Option<(f64, usize)> {
+    let mut cleaned = std::string::String::new();
+
+    // The integer part is optional so that leading-dot literals like `.5`
+    // are accepted, mirroring `scan_hex_float`'s handling of the mantissa.
+    let (int_digits, mut offset) = scan_digit_run(s, 0).unwrap_or_default();
+    let has_int_digits = !int_digits.is_empty();
+    cleaned.push_str(&int_digits);
+
+    let mut has_frac_digits = false;
+    if s[offset..].starts_with('.') {
+        if let Some((frac_digits, next)) = scan_digit_run(s, offset + 1) {
+            has_frac_digits = true;
+            cleaned.push('.');
+            cleaned.push_str(&frac_digits);
+            offset = next;
+        }
+    }
+
+    if !has_int_digits && !has_frac_digits {
+        return None;
+    }
+
+    if matches!(s.as_bytes().get(offset), Some(b'e' | b'E')) {
+        let mut exp_offset = offset + 1;
+        let mut exp = std::string::String::new();
+        if matches!(s.as_bytes().get(exp_offset), Some(b'+' | b'-')) {
+            exp.push(s.as_bytes()[exp_offset] as char);
+            exp_offset += 1;
+        }
+        if let Some((exp_digits, next)) = scan_digit_run(s, exp_offset) {
+            exp.push_str(&exp_digits);
+            cleaned.push('e');
+            cleaned.push_str(&exp);
+            offset = next;
+        }
+    }
+
+    let value = cleaned.parse().ok()?;
+    Some((value, offset))
+}
+
+/// Parse the body of a hexadecimal float literal (`1.8p3`, i.e. everything
+/// after the `0x`/`0X` prefix) starting at the beginning of `s`. The `p`/`P`
+/// binary exponent is mandatory, per C99 hex float syntax. Returns the value
+/// and the number of bytes of `s` consumed (not counting the `0x` prefix).
+fn scan_hex_float(s: &str) -> Option<(f64, usize)> {
+    let (int_digits, mut offset) = scan_hex_digit_run(s, 0).unwrap_or_default();
+
+    let mut frac_digits = std::string::String::new();
+    if s[offset..].starts_with('.') {
+        if let Some((digits, next)) = scan_hex_digit_run(s, offset + 1) {
+            frac_digits = digits;
+            offset = next;
+        } else {
+            offset += 1;
+        }
+    }
+    if int_digits.is_empty() && frac_digits.is_empty() {
+        return None;
+    }
+
+    if !matches!(s.as_bytes().get(offset), Some(b'p' | b'P')) {
+        return None;
+    }
+    let mut exp_offset = offset + 1;
+    let exp_sign = match s.as_bytes().get(exp_offset) {
+        Some(b'-') => {
+            exp_offset += 1;
+            -1
+        }
+        Some(b'+') => {
+            exp_offset += 1;
+            1
+        }
+        _ => 1,
+    };
+    let (exp_digits, next) = scan_digit_run(s, exp_offset)?;
+    let exponent: i32 = exp_digits.parse().ok()?;
+
+    let mantissa = hex_mantissa_to_f64(&int_digits, &frac_digits);
+    Some((mantissa * 2f64.powi(exp_sign * exponent), next))
+}
+
+/// Combine hex integer and fractional digit strings into their `f64` value,
+/// e.g. `("1", "8")` (from `1.8`) becomes `1.5`.
+fn hex_mantissa_to_f64(int_digits: &str, frac_digits: &str) -> f64 {
+    let mut value = 0.0;
+    for ch in int_digits.chars() {
+        value = value * 16.0 + f64::from(ch.to_digit(16).unwrap_or_default());
+    }
+    let mut scale = 1.0 / 16.0;
+    for ch in frac_digits.chars() {
+        value += f64::from(ch.to_digit(16).unwrap_or_default()) * scale;
+        scale /= 16.0;
+    }
+    value
 }
 
 // ```c
 // MRB_API double mrb_cstr_to_dbl(mrb_state *mrb, const char *s, mrb_bool badcheck)
 // ```
-//
-// NOTE: not implemented
+#[no_mangle]
+unsafe extern "C" fn mrb_cstr_to_dbl(
+    mrb: *mut sys::mrb_state,
+    s: *const c_char,
+    badcheck: sys::mrb_bool,
+) -> c_double {
+    unwrap_interpreter!(mrb, to => guard, or_else = 0.0);
+    let cstr = CStr::from_ptr(s);
+
+    match scan_ruby_float(cstr.to_bytes()) {
+        Some((num, true)) => num,
+        Some((num, false)) if !badcheck => num,
+        None if !badcheck => 0.0,
+        Some(_) | None => {
+            let err = ArgumentError::with_message("invalid value for Float()");
+            error::raise(guard, err);
+        }
+    }
+}
 
 // ```c
 // MRB_API double mrb_str_to_dbl(mrb_state *mrb, mrb_value str, mrb_bool badcheck)
 // ```
 #[no_mangle]
-unsafe extern "C" fn mrb_str_to_dbl(mrb: *mut sys::mrb_state, s: sys::mrb_value, badcheck: sys::mrb_bool) -> c_double {
+unsafe extern "C" fn mrb_str_to_dbl(
+    mrb: *mut sys::mrb_state,
+    s: sys::mrb_value,
+    badcheck: sys::mrb_bool,
+) -> c_double {
     unwrap_interpreter!(mrb, to => guard, or_else = 0.0);
     let mut s = Value::from(s);
     let s = if let Ok(s) = String::unbox_from_value(&mut s, &mut guard) {
@@ -560,20 +1067,15 @@ unsafe extern "C" fn mrb_str_to_dbl(mrb: *mut sys::mrb_state, s: sys::mrb_value,
     } else {
         return 0.0;
     };
-    if let Ok(s) = str::from_utf8(s.as_slice()) {
-        if let Ok(num) = s.parse::<c_double>() {
-            num
-        } else if badcheck {
-            let err = ArgumentError::with_message("invalid number");
+
+    match scan_ruby_float(s.as_slice()) {
+        Some((num, true)) => num,
+        Some((num, false)) if !badcheck => num,
+        None if !badcheck => 0.0,
+        Some(_) | None => {
+            let err = ArgumentError::with_message("invalid value for Float()");
             error::raise(guard, err);
-        } else {
-            0.0
         }
-    } else if badcheck {
-        let err = ArgumentError::with_message("invalid number");
-        error::raise(guard, err);
-    } else {
-        0.0
     }
 }
 
@@ -595,11 +1097,32 @@ unsafe extern "C" fn mrb_str_cat(
         // SAFETY: The string is repacked before any intervening uses of
         // `interp` which means no mruby heap allocations can occur.
         let string_mut = string.as_inner_mut();
-        string_mut.extend_from_slice(slice);
+        let requested = string_mut.len().saturating_add(len);
+        let result = if requested > isize::MAX as usize {
+            Err(CapacityError::TooBig)
+        } else {
+            string_mut
+                .try_reserve(len)
+                .map_err(CapacityError::OutOfMemory)
+        };
+        if result.is_ok() {
+            string_mut.extend_from_slice(slice);
+        }
         let inner = string.take();
-        let value = String::box_into_value(inner, s, &mut guard).expect("String reboxing should not fail");
-
-        value.inner()
+        let value =
+            String::box_into_value(inner, s, &mut guard).expect("String reboxing should not fail");
+
+        match result {
+            Ok(()) => value.inner(),
+            Err(CapacityError::TooBig) => {
+                let err = RangeError::with_message("string too big");
+                error::raise(guard, err);
+            }
+            Err(CapacityError::OutOfMemory(_)) => {
+                let err = NoMemoryError::with_message("out of memory");
+                error::raise(guard, err);
+            }
+        }
     } else {
         s.inner()
     }
@@ -663,3 +1186,90 @@ unsafe extern "C" fn mrb_gc_free_str(mrb: *mut sys::mrb_state, string: *mut sys:
     };
     drop(String::from_raw_parts(raw_parts));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_ruby_integer_hex_with_explicit_base() {
+        assert_eq!(Some((0xff, true)), scan_ruby_integer(b"0xff", 16));
+    }
+
+    #[test]
+    fn scan_ruby_integer_underscore_digit_group_separator() {
+        assert_eq!(Some((1_000, true)), scan_ruby_integer(b"1_000", 0));
+    }
+
+    #[test]
+    fn scan_ruby_integer_to_i_style_stops_at_first_invalid_char() {
+        // `String#to_i` semantics: leading whitespace is trimmed, a run of
+        // valid digits is parsed, and the rest is ignored (`consumed_all`
+        // is `false`) rather than rejecting the whole string.
+        assert_eq!(Some((-42, false)), scan_ruby_integer(b"  -42abc", 0));
+    }
+
+    #[test]
+    fn scan_ruby_integer_rejects_empty_digit_run() {
+        assert_eq!(None, scan_ruby_integer(b"abc", 0));
+    }
+
+    #[test]
+    fn scan_ruby_integer_does_not_trim_unicode_whitespace() {
+        // MRI's `to_i` trims only ASCII whitespace; a leading U+00A0 (NBSP)
+        // is not a digit, sign, or skippable prefix, so nothing parses.
+        assert_eq!(None, scan_ruby_integer("\u{a0}42".as_bytes(), 0));
+    }
+
+    #[test]
+    fn scan_decimal_float_leading_dot() {
+        assert_eq!(Some((0.5, 2)), scan_decimal_float(".5"));
+    }
+
+    #[test]
+    fn scan_ruby_float_leading_dot_is_fully_consumed() {
+        assert_eq!(Some((0.5, true)), scan_ruby_float(b".5"));
+    }
+
+    #[test]
+    fn scan_ruby_float_rejects_bare_dot() {
+        assert_eq!(None, scan_ruby_float(b"."));
+    }
+
+    #[test]
+    fn indexable_len_counts_characters_not_bytes_for_utf8() {
+        let string = String::utf8("héllo".as_bytes().to_vec());
+        assert_eq!(Some(5), indexable_len(&string));
+    }
+
+    #[test]
+    fn indexable_len_counts_bytes_for_binary() {
+        let string = String::with_bytes_and_encoding("héllo".as_bytes().to_vec(), Encoding::Binary);
+        assert_eq!(Some(6), indexable_len(&string));
+    }
+
+    #[test]
+    fn span_to_byte_range_round_trips_multibyte_substring() {
+        // "héllo": `h`, `é` (2 bytes), `l`, `l`, `o` -- char index 1, length
+        // 1 is the accented character, not its first byte.
+        let string = String::utf8("héllo".as_bytes().to_vec());
+        let (start, end) = span_to_byte_range(&string, 1, 1).unwrap();
+        assert_eq!("é", str::from_utf8(&string.as_slice()[start..end]).unwrap());
+    }
+
+    #[test]
+    fn index_to_byte_offset_lands_on_character_boundaries() {
+        let string = String::utf8("héllo".as_bytes().to_vec());
+        assert_eq!(Some(0), index_to_byte_offset(&string, 0));
+        assert_eq!(Some(1), index_to_byte_offset(&string, 1));
+        // `é` is 2 bytes, so the next character starts at byte offset 3.
+        assert_eq!(Some(3), index_to_byte_offset(&string, 2));
+    }
+
+    #[test]
+    fn resolve_index_handles_negative_offsets() {
+        assert_eq!(Some(2), resolve_index(5, 2));
+        assert_eq!(Some(3), resolve_index(5, -2));
+        assert_eq!(None, resolve_index(5, -6));
+    }
+}
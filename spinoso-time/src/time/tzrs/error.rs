@@ -0,0 +1,130 @@
+use std::error;
+use std::fmt;
+
+/// Sum type of all errors possible when constructing or operating on a
+/// [`Time`](super::Time) or [`Offset`](super::Offset).
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TimeError {
+    /// An offset was requested that is outside of the range representable by
+    /// a fixed offset.
+    TzOutOfRangeError(TzOutOfRangeError),
+    /// A time zone string could not be parsed into an [`Offset`](super::Offset).
+    TzStringError(TzStringError),
+    /// A named time zone could not be resolved from the system tzdb.
+    TzUnknownNameError(TzUnknownNameError),
+    /// An unexpected or unclassified error occurred.
+    Unknown,
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TzOutOfRangeError(err) => err.fmt(f),
+            Self::TzStringError(err) => err.fmt(f),
+            Self::TzUnknownNameError(err) => err.fmt(f),
+            Self::Unknown => f.write_str("Unknown time error"),
+        }
+    }
+}
+
+impl error::Error for TimeError {}
+
+impl From<TzOutOfRangeError> for TimeError {
+    #[inline]
+    fn from(err: TzOutOfRangeError) -> Self {
+        Self::TzOutOfRangeError(err)
+    }
+}
+
+impl From<TzStringError> for TimeError {
+    #[inline]
+    fn from(err: TzStringError) -> Self {
+        Self::TzStringError(err)
+    }
+}
+
+impl From<TzUnknownNameError> for TimeError {
+    #[inline]
+    fn from(err: TzUnknownNameError) -> Self {
+        Self::TzUnknownNameError(err)
+    }
+}
+
+/// Error that indicates an offset was out of the valid range for a fixed
+/// offset, which is `-86399..=86399` seconds.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct TzOutOfRangeError {
+    _private: (),
+}
+
+impl TzOutOfRangeError {
+    /// Construct a new `TzOutOfRangeError`.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl fmt::Display for TzOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Timezone offset out of expected range")
+    }
+}
+
+impl error::Error for TzOutOfRangeError {}
+
+/// Error that indicates a timezone string could not be parsed.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct TzStringError {
+    _private: (),
+}
+
+impl TzStringError {
+    /// Construct a new `TzStringError`.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl fmt::Display for TzStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Unable to parse a timezone from the given string")
+    }
+}
+
+impl error::Error for TzStringError {}
+
+/// Error that indicates a named IANA time zone could not be resolved from
+/// the system tzdb.
+///
+/// This is distinct from [`TzStringError`], which indicates the input could
+/// not be parsed as any recognized offset form at all. `TzUnknownNameError`
+/// indicates the input was a plausible zone name (e.g. `"America/Denver"`)
+/// that the tzdb lookup does not recognize.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TzUnknownNameError {
+    name: std::string::String,
+}
+
+impl TzUnknownNameError {
+    /// Construct a new `TzUnknownNameError` for the given zone name.
+    #[inline]
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for TzUnknownNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown time zone name: {}", self.name)
+    }
+}
+
+impl error::Error for TzUnknownNameError {}
@@ -0,0 +1,8 @@
+mod error;
+mod offset;
+mod strftime;
+mod time;
+
+pub use error::{TimeError, TzOutOfRangeError, TzStringError, TzUnknownNameError};
+pub use offset::{Offset, MAX_OFFSET_SECONDS, MIN_OFFSET_SECONDS};
+pub use time::Time;
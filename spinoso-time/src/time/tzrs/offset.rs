@@ -7,8 +7,10 @@ use tz::timezone::{LocalTimeType, TimeZoneRef};
 #[cfg(feature = "tzrs-local")]
 use tzdb::local_tz;
 use tzdb::time_zone::etc::GMT;
+#[cfg(feature = "tzrs-named")]
+use tzdb::tz_by_name;
 
-pub use super::error::{TimeError, TzOutOfRangeError, TzStringError};
+pub use super::error::{TimeError, TzOutOfRangeError, TzStringError, TzUnknownNameError};
 
 const SECONDS_IN_MINUTE: i32 = 60;
 const SECONDS_IN_HOUR: i32 = SECONDS_IN_MINUTE * 60;
@@ -65,7 +67,7 @@ fn local_time_zone() -> TimeZoneRef<'static> {
 /// Note: the actual seconds element is effectively ignored here
 #[inline]
 #[must_use]
-fn offset_hhmm_from_seconds(seconds: i32) -> String {
+pub(crate) fn offset_hhmm_from_seconds(seconds: i32) -> String {
     let flag = if seconds < 0 { '-' } else { '+' };
     let minutes = seconds.abs() / 60;
 
@@ -93,6 +95,15 @@ enum OffsetType {
     Fixed(LocalTimeType),
     /// A time zone based offset.
     Tz(TimeZoneRef<'static>),
+    /// A "no offset information" sentinel, as produced by parsing a literal
+    /// `-0000`/`-00:00` offset string.
+    ///
+    /// This behaves like a zero [`Fixed`](Self::Fixed) offset for arithmetic
+    /// and projection purposes, but is reported distinctly from both `Utc`
+    /// and a real `+00:00` fixed offset so that round-tripping RFC 2822/ISO
+    /// 8601 timestamps with an unknown local offset stays faithful to their
+    /// source.
+    Unknown(LocalTimeType),
 }
 
 impl Offset {
@@ -115,7 +126,9 @@ impl Offset {
     #[inline]
     #[must_use]
     pub fn utc() -> Self {
-        Self { inner: OffsetType::Utc }
+        Self {
+            inner: OffsetType::Utc,
+        }
     }
 
     /// Generate an offset based on the detected local time zone of the system.
@@ -200,6 +213,103 @@ impl Offset {
         })
     }
 
+    /// Construct an `Offset` from a permissive ISO 8601 offset string.
+    ///
+    /// Unlike the strict `TryFrom<&str>` implementation, which requires
+    /// exactly `[+/-]HH[:]MM`, this accepts the wider set of forms MRI's
+    /// `Time.new` accepts:
+    ///
+    /// - Hour-only offsets, e.g. `+09` / `-05` (minutes default to `00`).
+    /// - Offsets carrying seconds, e.g. `+09:00:00` or `+090030`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_time::tzrs::Offset;
+    /// let offset = Offset::try_from_iso8601_permissive("+09").unwrap();
+    /// let offset2 = Offset::try_from_iso8601_permissive("+09:00:00").unwrap();
+    /// assert_eq!(offset, offset2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimeError::TzStringError`] if `input` does not match a
+    /// recognized permissive offset form, or a
+    /// [`TimeError::TzOutOfRangeError`] if the resulting offset is outside of
+    /// [`MIN_OFFSET_SECONDS`] to [`MAX_OFFSET_SECONDS`].
+    #[inline]
+    pub fn try_from_iso8601_permissive(input: &str) -> Result<Self, TimeError> {
+        static PERMISSIVE_MATCHER: Lazy<Regex> = Lazy::new(|| {
+            // regex must compile
+            Regex::new(
+                r"^([\-\+]{1})([[:digit:]]{2})(?::?([[:digit:]]{2})(?::?([[:digit:]]{2}))?)?$",
+            )
+            .unwrap()
+        });
+
+        let caps = PERMISSIVE_MATCHER
+            .captures(input)
+            .ok_or_else(TzStringError::new)?;
+
+        let sign = if &caps[1] == "+" { 1 } else { -1 };
+        let hours = caps[2].parse::<i32>().expect("Two ASCII digits fit in i32");
+        let minutes = caps
+            .get(3)
+            .map(|m| {
+                m.as_str()
+                    .parse::<i32>()
+                    .expect("Two ASCII digits fit in i32")
+            })
+            .unwrap_or(0);
+        let seconds = caps
+            .get(4)
+            .map(|m| {
+                m.as_str()
+                    .parse::<i32>()
+                    .expect("Two ASCII digits fit in i32")
+            })
+            .unwrap_or(0);
+
+        if !(0..=23).contains(&hours)
+            || !(0..=59).contains(&minutes)
+            || !(0..=59).contains(&seconds)
+        {
+            return Err(TzOutOfRangeError::new().into());
+        }
+
+        let offset_seconds =
+            sign * (hours * SECONDS_IN_HOUR + minutes * SECONDS_IN_MINUTE + seconds);
+        Self::fixed(offset_seconds)
+    }
+
+    /// Generate the "no offset information" sentinel offset.
+    ///
+    /// This is produced when parsing a literal `-0000`/`-00:00` offset
+    /// string, which MRI and RFC 2822 treat as "local time whose offset is
+    /// unknown" — distinct from both a real `+00:00` fixed offset and
+    /// UTC/Zulu time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_time::tzrs::Offset;
+    /// let offset = Offset::unknown();
+    /// assert!(!offset.is_utc());
+    /// assert!(!offset.offset_is_known());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn unknown() -> Self {
+        // Creation of the `LocalTimeType` is never expected to fail since `0`
+        // is always in range and `-0000` uses only designation characters
+        // (alphanumerics, `+`, `-`) that `LocalTimeType` accepts.
+        let local_time_type = LocalTimeType::new(0, false, Some(b"-0000"))
+            .expect("Failed to build LocalTimeType for unknown offset");
+        Self {
+            inner: OffsetType::Unknown(local_time_type),
+        }
+    }
+
     /// Generate an offset based on a provided [`TimeZoneRef`].
     ///
     /// This can be combined with [`tzdb`] to generate offsets based on
@@ -212,6 +322,35 @@ impl Offset {
         }
     }
 
+    /// Generate an offset from an IANA time zone name, e.g. `"America/New_York"`
+    /// or `"Europe/Paris"`.
+    ///
+    /// The name is resolved via [`tzdb::tz_by_name`], so the resulting offset
+    /// tracks real DST transitions for the named zone rather than applying a
+    /// single fixed offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "tzrs-named")]
+    /// # {
+    /// # use spinoso_time::tzrs::Offset;
+    /// let offset = Offset::named("America/New_York").unwrap();
+    /// assert!(!offset.is_utc());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimeError::TzUnknownNameError`] when `name` does not match
+    /// a time zone known to the system tzdb.
+    #[cfg(feature = "tzrs-named")]
+    #[inline]
+    pub fn named(name: &str) -> Result<Self, TimeError> {
+        let tz = tz_by_name(name).ok_or_else(|| TzUnknownNameError::new(name))?;
+        Ok(Self::tz(tz))
+    }
+
     /// Returns whether this offset is UTC.
     ///
     /// # Examples
@@ -234,6 +373,26 @@ impl Offset {
         matches!(self.inner, OffsetType::Utc)
     }
 
+    /// Returns whether this offset carries known offset information.
+    ///
+    /// Returns `false` only for the [`Offset::unknown`] sentinel produced by
+    /// parsing a literal `-0000`/`-00:00` offset string. All other offsets,
+    /// including a real `+00:00` fixed offset and UTC, report `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_time::tzrs::Offset;
+    /// assert!(Offset::utc().offset_is_known());
+    /// assert!(Offset::fixed(0).unwrap().offset_is_known());
+    /// assert!(!Offset::unknown().offset_is_known());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn offset_is_known(&self) -> bool {
+        !matches!(self.inner, OffsetType::Unknown(_))
+    }
+
     /// Returns a `TimeZoneRef` which can be used to generate and project
     /// _time_.
     #[inline]
@@ -241,7 +400,7 @@ impl Offset {
     pub(crate) fn time_zone_ref(&self) -> TimeZoneRef<'_> {
         match self.inner {
             OffsetType::Utc => TimeZoneRef::utc(),
-            OffsetType::Fixed(ref local_time_type) => {
+            OffsetType::Fixed(ref local_time_type) | OffsetType::Unknown(ref local_time_type) => {
                 match TimeZoneRef::new(&[], slice::from_ref(local_time_type), &[], &None) {
                     Ok(tz) => tz,
                     Err(_) => GMT,
@@ -305,6 +464,10 @@ impl TryFrom<&str> for Offset {
             // => true
             // ```
             "Z" | "UTC" => Ok(Self::utc()),
+            // MRI and RFC 2822 treat a literal `-0000`/`-00:00` offset as
+            // "local time whose offset is unknown", which is distinct from
+            // the real zero offset produced by `+0000`/`+00:00`.
+            "-0000" | "-00:00" => Ok(Self::unknown()),
             _ => {
                 // With `Regex`, `\d` is a "Unicode friendly" Perl character
                 // class which matches Unicode property `Nd`. The `Nd` property
@@ -322,7 +485,32 @@ impl TryFrom<&str> for Offset {
                     Regex::new(r"^([\-\+]{1})([[:digit:]]{2}):?([[:digit:]]{2})$").unwrap()
                 });
 
-                let caps = HH_MM_MATCHER.captures(input).ok_or_else(TzStringError::new)?;
+                let caps = match HH_MM_MATCHER.captures(input) {
+                    Some(caps) => caps,
+                    None => {
+                        #[cfg(feature = "tzrs-named")]
+                        {
+                            // Only a string shaped like an IANA zone name is looked up,
+                            // propagating `Self::named`'s own error so a plausible but
+                            // unknown zone name surfaces as `TzUnknownNameError`, distinct
+                            // from a string that doesn't even look like an offset or zone
+                            // name, which stays a `TzStringError`.
+                            let looks_like_zone_name = input
+                                .starts_with(|c: char| c.is_ascii_alphabetic())
+                                && input.chars().all(|c| {
+                                    c.is_ascii_alphanumeric() || matches!(c, '/' | '_' | '-' | '+')
+                                });
+                            if looks_like_zone_name {
+                                return Self::named(input);
+                            }
+                            return Err(TzStringError::new().into());
+                        }
+                        #[cfg(not(feature = "tzrs-named"))]
+                        {
+                            return Err(TzStringError::new().into());
+                        }
+                    }
+                };
 
                 // Special handling of the +/- sign is required because `-00:30`
                 // must parse to a negative offset and `i32::from_str_radix`
@@ -340,7 +528,8 @@ impl TryFrom<&str> for Offset {
                 // - `00:00` to `00:59`
                 // - `00:00` to `23:59`
                 if (0..=23).contains(&hours) && (0..=59).contains(&minutes) {
-                    let offset_seconds: i32 = sign * ((hours * SECONDS_IN_HOUR) + (minutes * SECONDS_IN_MINUTE));
+                    let offset_seconds: i32 =
+                        sign * ((hours * SECONDS_IN_HOUR) + (minutes * SECONDS_IN_MINUTE));
                     Ok(Self::fixed(offset_seconds)?)
                 } else {
                     Err(TzOutOfRangeError::new().into())
@@ -399,15 +588,21 @@ mod tests {
 
     fn offset_seconds_from_fixed_offset(input: &str) -> Result<i32, TimeError> {
         let offset = Offset::try_from(input)?;
+        offset_seconds_from_fixed_offset_value(&offset).ok_or(TimeError::Unknown)
+    }
+
+    fn offset_seconds_from_fixed_offset_value(offset: &Offset) -> Option<i32> {
         let local_time_type = offset.time_zone_ref().local_time_types()[0];
-        Ok(local_time_type.ut_offset())
+        Some(local_time_type.ut_offset())
     }
 
     fn fixed_offset_name(offset_seconds: i32) -> Result<String, TimeError> {
         let offset = Offset::fixed(offset_seconds)?;
 
         match offset.inner {
-            OffsetType::Fixed(ref local_time_type) => Ok(local_time_type.time_zone_designation().to_string()),
+            OffsetType::Fixed(ref local_time_type) => {
+                Ok(local_time_type.time_zone_designation().to_string())
+            }
             _ => unreachable!(),
         }
     }
@@ -437,6 +632,41 @@ mod tests {
         assert!(offset.is_utc());
     }
 
+    #[test]
+    fn unknown_is_not_utc_and_not_known() {
+        let offset = Offset::unknown();
+        assert!(!offset.is_utc());
+        assert!(!offset.offset_is_known());
+    }
+
+    #[test]
+    fn fixed_and_utc_offsets_are_known() {
+        assert!(Offset::utc().offset_is_known());
+        assert!(Offset::fixed(0).unwrap().offset_is_known());
+    }
+
+    #[test]
+    fn from_str_negative_zero_is_unknown_offset() {
+        let offset = Offset::try_from("-0000").unwrap();
+        assert!(!offset.is_utc());
+        assert!(!offset.offset_is_known());
+
+        let offset = Offset::try_from("-00:00").unwrap();
+        assert!(!offset.is_utc());
+        assert!(!offset.offset_is_known());
+    }
+
+    #[test]
+    fn from_str_positive_zero_is_known_fixed_offset() {
+        let offset = Offset::try_from("+0000").unwrap();
+        assert!(!offset.is_utc());
+        assert!(offset.offset_is_known());
+
+        let offset = Offset::try_from("+00:00").unwrap();
+        assert!(!offset.is_utc());
+        assert!(offset.offset_is_known());
+    }
+
     #[test]
     fn from_str_hh_mm() {
         assert_eq!(Some(0), offset_seconds_from_fixed_offset("+0000").ok());
@@ -501,6 +731,56 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn permissive_iso8601_hour_only() {
+        let offset = Offset::try_from_iso8601_permissive("+09").unwrap();
+        assert_eq!(
+            Some(9 * 3600),
+            offset_seconds_from_fixed_offset_value(&offset)
+        );
+
+        let offset = Offset::try_from_iso8601_permissive("-05").unwrap();
+        assert_eq!(
+            Some(-5 * 3600),
+            offset_seconds_from_fixed_offset_value(&offset)
+        );
+    }
+
+    #[test]
+    fn permissive_iso8601_with_seconds() {
+        let offset = Offset::try_from_iso8601_permissive("+09:00:00").unwrap();
+        assert_eq!(
+            Some(9 * 3600),
+            offset_seconds_from_fixed_offset_value(&offset)
+        );
+
+        let offset = Offset::try_from_iso8601_permissive("+090030").unwrap();
+        assert_eq!(
+            Some(9 * 3600 + 30),
+            offset_seconds_from_fixed_offset_value(&offset)
+        );
+    }
+
+    #[test]
+    fn permissive_iso8601_rejects_invalid() {
+        assert!(matches!(
+            Offset::try_from_iso8601_permissive("bogus").unwrap_err(),
+            TimeError::TzStringError(_)
+        ));
+    }
+
+    #[test]
+    fn strict_try_from_does_not_accept_permissive_forms() {
+        assert!(matches!(
+            Offset::try_from("+09").unwrap_err(),
+            TimeError::TzStringError(_)
+        ));
+        assert!(matches!(
+            Offset::try_from("+09:00:00").unwrap_err(),
+            TimeError::TzStringError(_)
+        ));
+    }
+
     #[test]
     fn from_str_invalid_fixed_strings() {
         let invalid_fixed_strings = [
@@ -552,6 +832,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[cfg(feature = "tzrs-named")]
+    fn from_str_falls_back_to_named_zone() {
+        let offset = Offset::try_from("America/New_York").unwrap();
+        assert!(!offset.is_utc());
+    }
+
+    #[test]
+    #[cfg(feature = "tzrs-named")]
+    fn from_str_unknown_named_zone_reports_unknown_name_error() {
+        // `Time.new(..., "America/Bogus")` should surface a
+        // `TzUnknownNameError`, not the generic `TzStringError`, since
+        // "America/Bogus" is a plausible zone name the tzdb just doesn't
+        // recognize, as opposed to a string that isn't an offset or zone
+        // name at all.
+        assert!(matches!(
+            Offset::try_from("America/Bogus").unwrap_err(),
+            TimeError::TzUnknownNameError(_)
+        ));
+    }
+
     #[test]
     fn fixed_time_zone_designation() {
         assert_eq!("+0000", fixed_offset_name(0).unwrap());
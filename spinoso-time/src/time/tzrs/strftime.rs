@@ -0,0 +1,330 @@
+//! `strftime`/`strptime` formatting support, driven by [`Offset`] zone data.
+//!
+//! This module implements the subset of the C/Ruby `strftime` conversion
+//! specifiers needed to render an [`Offset`]'s zone designation and
+//! [+/-]HHMM offset alongside the broken-down date/time fields of a `Time`.
+
+use std::fmt::Write as _;
+
+use super::error::{TimeError, TzStringError};
+use super::offset::Offset;
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The broken-down date/time fields needed to render a `strftime` format
+/// string, decoupled from the `Time` type so this module can be exercised
+/// independently of it.
+#[derive(Debug, Clone, Copy)]
+pub struct Pieces<'a> {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+    /// Day of the week, `0` for Sunday through `6` for Saturday.
+    pub weekday: u8,
+    /// Day of the year, `1`-indexed.
+    pub yday: u16,
+    /// Seconds since the Unix epoch.
+    pub unix_seconds: i64,
+    pub offset: &'a Offset,
+}
+
+/// Padding style for a numeric conversion specifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pad {
+    Zero,
+    Space,
+    None,
+}
+
+/// Render `format` against the given broken-down time `pieces`.
+///
+/// # Errors
+///
+/// Returns a [`TimeError::TzStringError`] if `format` contains a `%`
+/// directive that is not a recognized conversion specifier.
+pub fn strftime(format: &str, pieces: &Pieces<'_>) -> Result<String, TimeError> {
+    let mut out = String::with_capacity(format.len());
+    let mut chars = format.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            out.push(ch);
+            continue;
+        }
+
+        let mut pad = Pad::Zero;
+        let mut width: Option<usize> = None;
+
+        loop {
+            match chars.peek() {
+                Some('-') => {
+                    pad = Pad::None;
+                    chars.next();
+                }
+                Some('_') => {
+                    pad = Pad::Space;
+                    chars.next();
+                }
+                Some('0') => {
+                    pad = Pad::Zero;
+                    chars.next();
+                }
+                Some(c) if c.is_ascii_digit() => {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.peek() {
+                        if c.is_ascii_digit() {
+                            digits.push(*c);
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                    width = digits.parse().ok();
+                }
+                _ => break,
+            }
+        }
+
+        // `%:z` is a single combined specifier (colon-separated `[+/-]HH:MM`),
+        // distinct from the bare `%z` (`[+/-]HHMM`).
+        if chars.peek() == Some(&':') {
+            let mut lookahead = chars.clone();
+            lookahead.next();
+            if lookahead.next() == Some('z') {
+                chars.next();
+                chars.next();
+                out.push_str(&pieces.offset.hhmm_colon());
+                continue;
+            }
+        }
+
+        let Some(spec) = chars.next() else {
+            out.push('%');
+            break;
+        };
+
+        render_specifier(&mut out, spec, pad, width, pieces)?;
+    }
+
+    Ok(out)
+}
+
+fn render_specifier(
+    out: &mut String,
+    spec: char,
+    pad: Pad,
+    width: Option<usize>,
+    pieces: &Pieces<'_>,
+) -> Result<(), TimeError> {
+    match spec {
+        'Y' => push_padded(out, i64::from(pieces.year), width.unwrap_or(4), pad),
+        'm' => push_padded(out, i64::from(pieces.month), width.unwrap_or(2), pad),
+        'd' => push_padded(out, i64::from(pieces.day), width.unwrap_or(2), pad),
+        'H' => push_padded(out, i64::from(pieces.hour), width.unwrap_or(2), pad),
+        'M' => push_padded(out, i64::from(pieces.minute), width.unwrap_or(2), pad),
+        'S' => push_padded(out, i64::from(pieces.second), width.unwrap_or(2), pad),
+        'j' => push_padded(out, i64::from(pieces.yday), width.unwrap_or(3), pad),
+        's' => {
+            let _ = write!(out, "{}", pieces.unix_seconds);
+        }
+        'N' => {
+            let digits = width.unwrap_or(9).min(9);
+            let scaled = pieces.nanosecond as u64 / 10u64.pow((9 - digits) as u32);
+            let _ = write!(out, "{:0width$}", scaled, width = digits);
+        }
+        'p' => out.push_str(if pieces.hour < 12 { "AM" } else { "PM" }),
+        'A' => out.push_str(WEEKDAYS[usize::from(pieces.weekday % 7)]),
+        'a' => out.push_str(&WEEKDAYS[usize::from(pieces.weekday % 7)][..3]),
+        'B' => out.push_str(MONTHS[usize::from(pieces.month.saturating_sub(1) % 12)]),
+        'b' => out.push_str(&MONTHS[usize::from(pieces.month.saturating_sub(1) % 12)][..3]),
+        'z' => out.push_str(&pieces.offset.hhmm()),
+        ':' => {
+            // `%:z` is recognized in `strftime` before this function is
+            // called, so a `:` that reaches here is not followed by `z` and
+            // is not a valid specifier.
+            return Err(TzStringError::new().into());
+        }
+        'Z' => out.push_str(pieces.offset.time_zone_designation()),
+        '%' => out.push('%'),
+        _ => return Err(TzStringError::new().into()),
+    }
+    Ok(())
+}
+
+fn push_padded(out: &mut String, value: i64, width: usize, pad: Pad) {
+    match pad {
+        Pad::Zero => {
+            let _ = write!(out, "{:0width$}", value, width = width);
+        }
+        Pad::Space => {
+            let _ = write!(out, "{:>width$}", value, width = width);
+        }
+        Pad::None => {
+            let _ = write!(out, "{}", value);
+        }
+    }
+}
+
+/// The parsed result of a `strptime` scan of a `%z`/`%:z` offset token.
+#[derive(Debug, Clone, Copy)]
+pub struct ParsedOffset {
+    pub offset_seconds: i32,
+    /// Number of bytes of the input consumed by the match.
+    pub consumed: usize,
+}
+
+/// Parse a leading `[+/-]HH:MM` or `[+/-]HHMM` offset from `input`, as
+/// produced by `%z`/`%:z` in a `strftime`-formatted timestamp.
+///
+/// This reuses the same strict HH:MM scanning semantics as
+/// `Offset::try_from(&str)` so that timestamps formatted with `%z`/`%:z`
+/// round-trip through `strptime`.
+///
+/// # Errors
+///
+/// Returns a [`TimeError::TzStringError`] if `input` does not begin with a
+/// recognized offset.
+pub fn strptime_offset(input: &str) -> Result<ParsedOffset, TimeError> {
+    use once_cell::sync::Lazy;
+    use regex::Regex;
+
+    // Mirrors the `HH_MM_MATCHER` used by `Offset::try_from(&str)`, anchored
+    // at the start of the input only (not the end) so trailing format
+    // literals after `%z`/`%:z` don't get rejected.
+    static LEADING_HH_MM_MATCHER: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^([\-\+]{1})([[:digit:]]{2}):?([[:digit:]]{2})").unwrap());
+
+    let caps = LEADING_HH_MM_MATCHER
+        .captures(input)
+        .ok_or_else(TzStringError::new)?;
+    let whole = caps.get(0).expect("capture group 0 always matches");
+
+    let sign = if &caps[1] == "+" { 1 } else { -1 };
+    let hours: i32 = caps[2].parse().expect("two ASCII digits fit in i32");
+    let minutes: i32 = caps[3].parse().expect("two ASCII digits fit in i32");
+
+    if !(0..=23).contains(&hours) || !(0..=59).contains(&minutes) {
+        return Err(TzStringError::new().into());
+    }
+
+    let offset_seconds = sign * (hours * 3600 + minutes * 60);
+    Ok(ParsedOffset {
+        offset_seconds,
+        consumed: whole.as_str().len(),
+    })
+}
+
+impl Offset {
+    /// Render this offset as `[+/-]HHMM`, as used by `%z`.
+    #[inline]
+    #[must_use]
+    pub fn hhmm(&self) -> std::string::String {
+        let seconds = self.time_zone_ref().local_time_types()[0].ut_offset();
+        super::offset::offset_hhmm_from_seconds(seconds)
+    }
+
+    /// Render this offset as `[+/-]HH:MM`, as used by `%:z`.
+    #[inline]
+    #[must_use]
+    pub fn hhmm_colon(&self) -> std::string::String {
+        let hhmm = self.hhmm();
+        std::format!("{}:{}", &hhmm[..3], &hhmm[3..])
+    }
+
+    /// The zone designation used to render `%Z`, e.g. `UTC`, `+0150`, or
+    /// `EST`.
+    #[inline]
+    #[must_use]
+    pub fn time_zone_designation(&self) -> &str {
+        self.time_zone_ref().local_time_types()[0].time_zone_designation()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pieces(offset: &Offset) -> Pieces<'_> {
+        Pieces {
+            year: 2022,
+            month: 7,
+            day: 29,
+            hour: 12,
+            minute: 36,
+            second: 4,
+            nanosecond: 123_000_000,
+            weekday: 5,
+            yday: 210,
+            unix_seconds: 1_659_098_164,
+            offset,
+        }
+    }
+
+    #[test]
+    fn formats_date_and_time_fields() {
+        let offset = Offset::utc();
+        let pieces = pieces(&offset);
+        assert_eq!(
+            "2022-07-29 12:36:04",
+            strftime("%Y-%m-%d %H:%M:%S", &pieces).unwrap()
+        );
+    }
+
+    #[test]
+    fn formats_zone_designation_and_offsets() {
+        let offset = Offset::fixed(-5 * 3600).unwrap();
+        let pieces = pieces(&offset);
+        assert_eq!("-0500", strftime("%Z", &pieces).unwrap());
+        assert_eq!("-0500", strftime("%z", &pieces).unwrap());
+        assert_eq!("-05:00", strftime("%:z", &pieces).unwrap());
+    }
+
+    #[test]
+    fn formats_names_and_dash_flag() {
+        let offset = Offset::utc();
+        let pieces = pieces(&offset);
+        assert_eq!("Friday", strftime("%A", &pieces).unwrap());
+        assert_eq!("Jul", strftime("%b", &pieces).unwrap());
+        assert_eq!("PM", strftime("%p", &pieces).unwrap());
+        assert_eq!("29", strftime("%-d", &pieces).unwrap());
+    }
+
+    #[test]
+    fn strptime_offset_round_trips_strftime_z() {
+        let offset = Offset::fixed(9 * 3600 + 30 * 60).unwrap();
+        let rendered = offset.hhmm();
+        let parsed = strptime_offset(&rendered).unwrap();
+        assert_eq!(9 * 3600 + 30 * 60, parsed.offset_seconds);
+    }
+
+    #[test]
+    fn strptime_offset_rejects_malformed_input() {
+        assert!(strptime_offset("not-an-offset").is_err());
+    }
+}
@@ -0,0 +1,345 @@
+//! The [`Time`] type: a broken-down date/time paired with an [`Offset`].
+
+use super::error::{TimeError, TzStringError};
+use super::offset::Offset;
+use super::strftime::{self, strptime_offset, Pieces};
+
+/// A `Time` represents a date and time, to nanosecond precision, combined
+/// with an [`Offset`] from UTC.
+///
+/// # Examples
+///
+/// ```
+/// # use spinoso_time::tzrs::{Offset, Time, TimeError};
+/// # fn example() -> Result<(), TimeError> {
+/// let time = Time::new(2022, 7, 29, 12, 36, 4, 123_000_000, Offset::utc())?;
+/// assert!(time.is_utc());
+/// assert_eq!("2022-07-29 12:36:04", time.strftime("%Y-%m-%d %H:%M:%S")?);
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Time {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+    offset: Offset,
+}
+
+impl Time {
+    /// Construct a `Time` from broken-down date/time fields and an
+    /// [`Offset`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimeError::Unknown`] if any field is out of range for its
+    /// position (e.g. a `month` outside `1..=12`, or a `day` that does not
+    /// exist in the given `year`/`month`).
+    #[inline]
+    pub fn new(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+        offset: Offset,
+    ) -> Result<Self, TimeError> {
+        if !(1..=12).contains(&month) {
+            return Err(TimeError::Unknown);
+        }
+        if day < 1 || day > days_in_month(year, month) {
+            return Err(TimeError::Unknown);
+        }
+        if hour > 23 || minute > 59 || second > 60 {
+            // `60` accommodates a leap second.
+            return Err(TimeError::Unknown);
+        }
+        if nanosecond >= 1_000_000_000 {
+            return Err(TimeError::Unknown);
+        }
+
+        Ok(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond,
+            offset,
+        })
+    }
+
+    /// Returns whether this `Time`'s offset is UTC.
+    #[inline]
+    #[must_use]
+    pub fn is_utc(&self) -> bool {
+        self.offset.is_utc()
+    }
+
+    /// This `Time`'s offset from UTC.
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> &Offset {
+        &self.offset
+    }
+
+    fn unix_seconds(&self) -> i64 {
+        let days = days_from_civil(self.year, self.month, self.day);
+        // The fields are wall-clock time in `offset`; subtract the UTC
+        // offset of the same local time type that `%z` renders.
+        let utc_offset = self.offset.time_zone_ref().local_time_types()[0].ut_offset();
+        days * 86_400
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second)
+            - i64::from(utc_offset)
+    }
+
+    fn weekday(&self) -> u8 {
+        let days = days_from_civil(self.year, self.month, self.day);
+        // 1970-01-01 (`days == 0`) was a Thursday, weekday `4`.
+        u8::try_from(((days % 7) + 7 + 4) % 7).unwrap_or_default()
+    }
+
+    fn yday(&self) -> u16 {
+        let mut yday = u16::from(self.day);
+        for month in 1..self.month {
+            yday += u16::from(days_in_month(self.year, month));
+        }
+        yday
+    }
+
+    fn pieces(&self) -> Pieces<'_> {
+        Pieces {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+            hour: self.hour,
+            minute: self.minute,
+            second: self.second,
+            nanosecond: self.nanosecond,
+            weekday: self.weekday(),
+            yday: self.yday(),
+            unix_seconds: self.unix_seconds(),
+            offset: &self.offset,
+        }
+    }
+
+    /// Format this `Time` according to a `strftime`-style format string.
+    ///
+    /// See [`strftime::strftime`](super::strftime::strftime) for the set of
+    /// supported conversion specifiers.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimeError::TzStringError`] if `format` contains a `%`
+    /// directive that is not a recognized conversion specifier.
+    #[inline]
+    pub fn strftime(&self, format: &str) -> Result<String, TimeError> {
+        strftime::strftime(format, &self.pieces())
+    }
+
+    /// Parse a `Time` out of `input` according to a `strftime`-style
+    /// `format` string.
+    ///
+    /// Supports the numeric date/time specifiers (`%Y %m %d %H %M %S`), the
+    /// literal `%%`, and the offset specifiers `%z`/`%:z` (parsed via
+    /// [`strptime_offset`](super::strftime::strptime_offset)). Every other
+    /// byte in `format` is matched literally against `input`. The whole of
+    /// `input` must be consumed by `format` for the parse to succeed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimeError::TzStringError`] if `input` does not match
+    /// `format`, or contains an unsupported conversion specifier.
+    pub fn strptime(input: &str, format: &str) -> Result<Self, TimeError> {
+        let mut year = 1970_i32;
+        let mut month = 1_u8;
+        let mut day = 1_u8;
+        let mut hour = 0_u8;
+        let mut minute = 0_u8;
+        let mut second = 0_u8;
+        let mut offset = Offset::utc();
+
+        let mut fmt_chars = format.chars();
+        let mut rest = input;
+
+        while let Some(ch) = fmt_chars.next() {
+            if ch != '%' {
+                let mut input_chars = rest.chars();
+                if input_chars.next() != Some(ch) {
+                    return Err(TzStringError::new().into());
+                }
+                rest = input_chars.as_str();
+                continue;
+            }
+
+            let spec = fmt_chars.next().ok_or_else(TzStringError::new)?;
+            match spec {
+                'Y' => {
+                    let (value, next) = take_number(rest, 10)?;
+                    year = i32::try_from(value).map_err(|_| TzStringError::new())?;
+                    rest = next;
+                }
+                'm' => {
+                    let (value, next) = take_number(rest, 2)?;
+                    month = u8::try_from(value).map_err(|_| TzStringError::new())?;
+                    rest = next;
+                }
+                'd' => {
+                    let (value, next) = take_number(rest, 2)?;
+                    day = u8::try_from(value).map_err(|_| TzStringError::new())?;
+                    rest = next;
+                }
+                'H' => {
+                    let (value, next) = take_number(rest, 2)?;
+                    hour = u8::try_from(value).map_err(|_| TzStringError::new())?;
+                    rest = next;
+                }
+                'M' => {
+                    let (value, next) = take_number(rest, 2)?;
+                    minute = u8::try_from(value).map_err(|_| TzStringError::new())?;
+                    rest = next;
+                }
+                'S' => {
+                    let (value, next) = take_number(rest, 2)?;
+                    second = u8::try_from(value).map_err(|_| TzStringError::new())?;
+                    rest = next;
+                }
+                'z' => {
+                    let parsed = strptime_offset(rest)?;
+                    offset = Offset::fixed(parsed.offset_seconds)?;
+                    rest = &rest[parsed.consumed..];
+                }
+                ':' => {
+                    if fmt_chars.next() != Some('z') {
+                        return Err(TzStringError::new().into());
+                    }
+                    let parsed = strptime_offset(rest)?;
+                    offset = Offset::fixed(parsed.offset_seconds)?;
+                    rest = &rest[parsed.consumed..];
+                }
+                '%' => {
+                    let mut input_chars = rest.chars();
+                    if input_chars.next() != Some('%') {
+                        return Err(TzStringError::new().into());
+                    }
+                    rest = input_chars.as_str();
+                }
+                _ => return Err(TzStringError::new().into()),
+            }
+        }
+
+        if !rest.is_empty() {
+            return Err(TzStringError::new().into());
+        }
+
+        Self::new(year, month, day, hour, minute, second, 0, offset)
+    }
+}
+
+/// Consume up to `max_width` leading ASCII digits from `s`, returning the
+/// parsed value and the unconsumed remainder. Requires at least one digit.
+fn take_number(s: &str, max_width: usize) -> Result<(u32, &str), TimeError> {
+    let digit_count = s
+        .chars()
+        .take(max_width)
+        .take_while(char::is_ascii_digit)
+        .count();
+    if digit_count == 0 {
+        return Err(TzStringError::new().into());
+    }
+    let (digits, rest) = s.split_at(digit_count);
+    let value = digits.parse().map_err(|_| TzStringError::new())?;
+    Ok((value, rest))
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Days since the Unix epoch (`1970-01-01`) for the given proleptic
+/// Gregorian civil date, via Howard Hinnant's `days_from_civil` algorithm.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let y = if month <= 2 {
+        i64::from(year) - 1
+    } else {
+        i64::from(year)
+    };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400; // [0, 399]
+    let mp = (i64::from(month) + 9) % 12; // [0, 11]
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unix_epoch_is_thursday() {
+        let time = Time::new(1970, 1, 1, 0, 0, 0, 0, Offset::utc()).unwrap();
+        assert_eq!(0, time.unix_seconds());
+        assert_eq!(4, time.weekday());
+    }
+
+    #[test]
+    fn rejects_invalid_fields() {
+        assert!(Time::new(2022, 13, 1, 0, 0, 0, 0, Offset::utc()).is_err());
+        assert!(Time::new(2022, 2, 29, 0, 0, 0, 0, Offset::utc()).is_err());
+        assert!(Time::new(2020, 2, 29, 0, 0, 0, 0, Offset::utc()).is_ok());
+        assert!(Time::new(2022, 1, 1, 24, 0, 0, 0, Offset::utc()).is_err());
+    }
+
+    #[test]
+    fn strftime_round_trips_basic_format() {
+        let time = Time::new(2022, 7, 29, 12, 36, 4, 0, Offset::utc()).unwrap();
+        assert_eq!(
+            "2022-07-29 12:36:04",
+            time.strftime("%Y-%m-%d %H:%M:%S").unwrap()
+        );
+    }
+
+    #[test]
+    fn strptime_parses_basic_format() {
+        let time = Time::strptime("2022-07-29 12:36:04", "%Y-%m-%d %H:%M:%S").unwrap();
+        assert_eq!(
+            "2022-07-29 12:36:04",
+            time.strftime("%Y-%m-%d %H:%M:%S").unwrap()
+        );
+    }
+
+    #[test]
+    fn strptime_round_trips_offset() {
+        let time = Time::strptime("2022-07-29T12:36:04-0500", "%Y-%m-%dT%H:%M:%S%z").unwrap();
+        assert!(!time.is_utc());
+        assert_eq!("-0500", time.strftime("%z").unwrap());
+    }
+
+    #[test]
+    fn strptime_rejects_trailing_garbage() {
+        assert!(Time::strptime("2022-07-29 garbage", "%Y-%m-%d").is_err());
+    }
+}